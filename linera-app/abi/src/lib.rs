@@ -1,4 +1,7 @@
+use async_graphql::Enum;
+use linera_sdk::linera_base_types::{AccountSignature, Amount, ApplicationId, BcsHashable, BcsSignable, ChainId, CryptoHash};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 
 /// Trading signal extracted from tweets
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -7,7 +10,7 @@ pub struct Signal {
     pub influencer: String,
     pub token: String,
     pub contract: String,
-    pub sentiment: String,
+    pub sentiment: Sentiment,
     pub confidence: f64,
     pub timestamp: u64,
     pub tweet_url: String,
@@ -17,18 +20,591 @@ pub struct Signal {
     pub take_profit: Option<f64>,
     pub position_size: Option<f64>,
     pub leverage: Option<u8>,
-    pub platform: Option<String>, // "DEX" or "CEX"
+    pub platform: Option<Platform>,
+}
+
+// Typed enums below serialize as the strings clients already send
+// (lowercase, except `Platform`'s uppercase "DEX" and "CEX"), and parse the
+// legacy spellings case-insensitively, so the wire format is unchanged while
+// invalid values are rejected on deserialization.
+
+/// Sentiment of a trading call
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, Enum)]
+#[serde(try_from = "String", into = "String")]
+pub enum Sentiment {
+    Bullish,
+    Bearish,
+    Neutral,
+}
+
+impl Sentiment {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Sentiment::Bullish => "bullish",
+            Sentiment::Bearish => "bearish",
+            Sentiment::Neutral => "neutral",
+        }
+    }
+
+    /// Parse the sentiment strings produced by the AI parser, accepting
+    /// long/short and buy/sell synonyms
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "bullish" | "long" | "buy" => Some(Sentiment::Bullish),
+            "bearish" | "short" | "sell" => Some(Sentiment::Bearish),
+            "neutral" => Some(Sentiment::Neutral),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<String> for Sentiment {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Sentiment::parse(&value).ok_or_else(|| format!("unknown sentiment {:?}", value))
+    }
+}
+
+impl From<Sentiment> for String {
+    fn from(value: Sentiment) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+/// Venue a signal targets
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, Enum)]
+#[serde(try_from = "String", into = "String")]
+pub enum Platform {
+    Dex,
+    Cex,
+}
+
+impl Platform {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Platform::Dex => "DEX",
+            Platform::Cex => "CEX",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_uppercase().as_str() {
+            "DEX" => Some(Platform::Dex),
+            "CEX" => Some(Platform::Cex),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<String> for Platform {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Platform::parse(&value).ok_or_else(|| format!("unknown platform {:?}", value))
+    }
+}
+
+impl From<Platform> for String {
+    fn from(value: Platform) -> Self {
+        value.as_str().to_string()
+    }
+}
+
+/// Highest leverage a signal may suggest
+pub const MAX_SIGNAL_LEVERAGE: u8 = 100;
+
+/// Reasons a signal is rejected at submission
+#[derive(Clone, Debug, Error, PartialEq, Deserialize, Serialize)]
+pub enum SignalError {
+    #[error("confidence {0} is outside 0.0 - 1.0")]
+    InvalidConfidence(f64),
+    #[error("influencer is empty")]
+    MissingInfluencer,
+    #[error("invalid token symbol {0:?}")]
+    InvalidToken(String),
+    #[error("invalid contract address {0:?}")]
+    InvalidContract(String),
+    #[error("leverage {0} is outside 1 - 100")]
+    InvalidLeverage(u8),
+    #[error("prices must be positive")]
+    NonPositivePrice,
+    #[error("stop-loss, entry and take-profit are out of order for a {0:?} call")]
+    PriceOrdering(Sentiment),
+    #[error("signal for tweet already submitted as #{0}")]
+    DuplicateTweet(u64),
+    #[error("influencer already called this contract in signal #{0}")]
+    DuplicateCall(u64),
+    #[error("submitter is not authorised")]
+    UnauthorisedSubmitter,
+    #[error("submitter exceeded {0} signals per window")]
+    RateLimited(u32),
+    #[error("duplicates batch item {0}")]
+    DuplicateInBatch(usize),
+}
+
+/// Whether `address` looks like an EVM (0x + 40 hex) or Solana (base58,
+/// 32-44 chars) contract address
+pub fn is_valid_contract_address(address: &str) -> bool {
+    if let Some(hex) = address.strip_prefix("0x") {
+        return hex.len() == 40 && hex.chars().all(|c| c.is_ascii_hexdigit());
+    }
+    (32..=44).contains(&address.len())
+        && address
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() && !matches!(c, '0' | 'O' | 'I' | 'l'))
+}
+
+impl Signal {
+    /// Structural validation of a submitted signal
+    pub fn validate(&self) -> Result<(), SignalError> {
+        if !(0.0..=1.0).contains(&self.confidence) {
+            return Err(SignalError::InvalidConfidence(self.confidence));
+        }
+        let sentiment = self.sentiment;
+        if self.influencer.trim().is_empty() {
+            return Err(SignalError::MissingInfluencer);
+        }
+        let token = self.token.trim_start_matches('$');
+        if token.is_empty() || token.len() > 20 || !token.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(SignalError::InvalidToken(self.token.clone()));
+        }
+        if !is_valid_contract_address(&self.contract) {
+            return Err(SignalError::InvalidContract(self.contract.clone()));
+        }
+        if let Some(leverage) = self.leverage {
+            if leverage == 0 || leverage > MAX_SIGNAL_LEVERAGE {
+                return Err(SignalError::InvalidLeverage(leverage));
+            }
+        }
+
+        let prices = [self.stop_loss, self.entry_price, self.take_profit];
+        if prices.iter().flatten().any(|price| !(price.is_finite() && *price > 0.0)) {
+            return Err(SignalError::NonPositivePrice);
+        }
+        // Whatever subset of stop < entry < take-profit is given must be
+        // ordered, reversed for bearish calls
+        let ordered: Vec<f64> = prices.iter().flatten().copied().collect();
+        let in_order = match sentiment {
+            Sentiment::Bullish => ordered.windows(2).all(|pair| pair[0] < pair[1]),
+            Sentiment::Bearish => ordered.windows(2).all(|pair| pair[0] > pair[1]),
+            Sentiment::Neutral => true,
+        };
+        if !in_order {
+            return Err(SignalError::PriceOrdering(sentiment));
+        }
+        Ok(())
+    }
+
+    /// +1 for bullish calls, -1 for bearish calls, `None` for neutral calls
+    pub fn direction(&self) -> Option<f64> {
+        match self.sentiment {
+            Sentiment::Bullish => Some(1.0),
+            Sentiment::Bearish => Some(-1.0),
+            Sentiment::Neutral => None,
+        }
+    }
+
+    /// Return of the call at `price`, signed so that a correct call is positive
+    pub fn directional_return(&self, price: f64) -> Option<f64> {
+        let entry = self.entry_price.filter(|entry| *entry > 0.0)?;
+        Some(self.direction()? * (price - entry) / entry)
+    }
+}
+
+/// Anti-spam policy for signal submission, set by the admin
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SignalPolicy {
+    /// Maximum signals per submitter per rate window
+    pub max_signals_per_window: u32,
+    pub rate_window_micros: u64,
+    /// An influencer calling the same contract again within this window is
+    /// treated as a duplicate
+    pub duplicate_window_micros: u64,
+    /// Signals unresolved after this long expire
+    pub signal_lifetime_micros: u64,
+}
+
+impl Default for SignalPolicy {
+    fn default() -> Self {
+        SignalPolicy {
+            max_signals_per_window: 120,
+            rate_window_micros: 60_000_000,
+            duplicate_window_micros: 3_600_000_000,
+            signal_lifetime_micros: 7 * 24 * 3_600_000_000,
+        }
+    }
+}
+
+/// Fixed-window submission counter for one submitter
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RateWindow {
+    pub window_start: u64,
+    pub count: u32,
+}
+
+impl RateWindow {
+    /// Count a submission at `now`, returning false if it exceeds the limit
+    pub fn try_record(&mut self, now: u64, policy: &SignalPolicy) -> bool {
+        self.try_record_many(now, policy, 1)
+    }
+
+    /// Count `count` submissions at `now`, all or none, returning false if
+    /// they would exceed the limit
+    pub fn try_record_many(&mut self, now: u64, policy: &SignalPolicy, count: u32) -> bool {
+        if now >= self.window_start.saturating_add(policy.rate_window_micros) {
+            self.window_start = now;
+            self.count = 0;
+        }
+        if self.count.saturating_add(count) > policy.max_signals_per_window {
+            return false;
+        }
+        self.count += count;
+        true
+    }
+}
+
+// ============================================
+// INFLUENCER REPUTATION
+// ============================================
+
+/// Horizons at which a signal's realized return is measured
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+pub enum OutcomeHorizon {
+    OneHour,
+    OneDay,
+    OneWeek,
+}
+
+impl OutcomeHorizon {
+    /// Time after submission at which the horizon can be recorded (micros)
+    pub fn micros(&self) -> u64 {
+        const HOUR: u64 = 3_600_000_000;
+        match self {
+            OutcomeHorizon::OneHour => HOUR,
+            OutcomeHorizon::OneDay => 24 * HOUR,
+            OutcomeHorizon::OneWeek => 7 * 24 * HOUR,
+        }
+    }
+
+    /// Weight of this horizon in the reputation score; longer horizons
+    /// say more about a call than short-term noise
+    pub fn weight(&self) -> f64 {
+        match self {
+            OutcomeHorizon::OneHour => 1.0,
+            OutcomeHorizon::OneDay => 2.0,
+            OutcomeHorizon::OneWeek => 3.0,
+        }
+    }
+}
+
+/// Which protective target a signal's price reached first
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+pub enum TargetHit {
+    TakeProfit,
+    StopLoss,
+}
+
+/// Weight of a take-profit or stop-loss hit in the reputation score
+pub const TARGET_HIT_WEIGHT: f64 = 3.0;
+
+/// Prior applied to every influencer's score so a handful of lucky calls
+/// does not produce a top ranking: equivalent to this much weight at 50%
+pub const REPUTATION_PRIOR_WEIGHT: f64 = 6.0;
+
+/// Lifecycle of a signal, driven by reported prices
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+pub enum SignalLifecycle {
+    /// Waiting for price to reach the entry
+    Open,
+    /// Entry reached; waiting for take-profit or stop-loss
+    EntryHit,
+    TakeProfitHit,
+    StopHit,
+    /// Lifetime elapsed before take-profit or stop-loss
+    Expired,
+}
+
+impl SignalLifecycle {
+    pub fn is_resolved(&self) -> bool {
+        matches!(
+            self,
+            SignalLifecycle::TakeProfitHit | SignalLifecycle::StopHit | SignalLifecycle::Expired
+        )
+    }
+}
+
+/// Realized outcome of a signal, filled in as prices are reported
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SignalOutcome {
+    pub signal_id: u64,
+    pub influencer: String,
+    pub submitted_at: u64,
+    pub return_1h: Option<f64>,
+    pub return_24h: Option<f64>,
+    pub return_7d: Option<f64>,
+    pub target_hit: Option<TargetHit>,
+    // Lifecycle
+    pub status: SignalLifecycle,
+    pub entry_hit_at: Option<u64>,
+    /// Entry used for the realized return: the signal's entry price, or the
+    /// first reported price for calls without one
+    pub entry_fill_price: Option<f64>,
+    pub last_price: Option<f64>,
+    pub resolved_at: Option<u64>,
+    /// Directional return at resolution (0.34 = +34%)
+    pub realized_return: Option<f64>,
+}
+
+impl SignalOutcome {
+    pub fn new(signal_id: u64, influencer: String, submitted_at: u64) -> Self {
+        SignalOutcome {
+            signal_id,
+            influencer,
+            submitted_at,
+            return_1h: None,
+            return_24h: None,
+            return_7d: None,
+            target_hit: None,
+            status: SignalLifecycle::Open,
+            entry_hit_at: None,
+            entry_fill_price: None,
+            last_price: None,
+            resolved_at: None,
+            realized_return: None,
+        }
+    }
+
+    /// Advance the lifecycle on a price observation at `now`. Returns true
+    /// if the status changed.
+    pub fn observe_price(&mut self, signal: &Signal, price: f64, now: u64, lifetime_micros: u64) -> bool {
+        if self.status.is_resolved() {
+            return false;
+        }
+        self.last_price = Some(price);
+        if self.expire_if_due(signal, now, lifetime_micros) {
+            return true;
+        }
+        let Some(direction) = signal.direction() else {
+            return false;
+        };
+        let before = self.status;
+
+        if self.status == SignalLifecycle::Open {
+            // Longs enter at or below the entry, shorts at or above it
+            let entry = signal.entry_price.unwrap_or(price);
+            if direction * (price - entry) > 0.0 {
+                return false;
+            }
+            self.status = SignalLifecycle::EntryHit;
+            self.entry_hit_at = Some(now);
+            self.entry_fill_price = Some(entry);
+        }
+
+        // The tick that reaches the entry may also reach a target
+        let Some(entry) = self.entry_fill_price else {
+            return self.status != before;
+        };
+        if let Some(take_profit) = signal.take_profit.filter(|tp| direction * (price - tp) >= 0.0) {
+            self.resolve(SignalLifecycle::TakeProfitHit, direction * (take_profit - entry) / entry, now);
+            self.target_hit = Some(TargetHit::TakeProfit);
+        } else if let Some(stop_loss) = signal.stop_loss.filter(|sl| direction * (price - sl) <= 0.0) {
+            self.resolve(SignalLifecycle::StopHit, direction * (stop_loss - entry) / entry, now);
+            self.target_hit = Some(TargetHit::StopLoss);
+        }
+        self.status != before
+    }
+
+    /// Resolve at a take-profit or stop-loss reported directly rather than
+    /// observed through prices. Returns true if the status changed.
+    pub fn apply_target_hit(&mut self, signal: &Signal, hit: TargetHit, now: u64) -> bool {
+        if self.status.is_resolved() {
+            return false;
+        }
+        let target = match hit {
+            TargetHit::TakeProfit => signal.take_profit,
+            TargetHit::StopLoss => signal.stop_loss,
+        };
+        let (Some(direction), Some(target), Some(entry)) =
+            (signal.direction(), target, self.entry_fill_price.or(signal.entry_price))
+        else {
+            return false;
+        };
+        // A target is only reached after the entry
+        if self.entry_fill_price.is_none() {
+            self.entry_hit_at = Some(now);
+            self.entry_fill_price = Some(entry);
+        }
+        let status = match hit {
+            TargetHit::TakeProfit => SignalLifecycle::TakeProfitHit,
+            TargetHit::StopLoss => SignalLifecycle::StopHit,
+        };
+        self.resolve(status, direction * (target - entry) / entry, now);
+        self.target_hit = Some(hit);
+        true
+    }
+
+    /// Expire an unresolved signal whose lifetime has elapsed, marking to
+    /// the last reported price if the entry was reached. Returns true if it
+    /// expired.
+    pub fn expire_if_due(&mut self, signal: &Signal, now: u64, lifetime_micros: u64) -> bool {
+        if self.status.is_resolved() || now < self.submitted_at.saturating_add(lifetime_micros) {
+            return false;
+        }
+        self.status = SignalLifecycle::Expired;
+        self.resolved_at = Some(now);
+        self.realized_return = match (self.entry_fill_price, self.last_price, signal.direction()) {
+            (Some(entry), Some(last), Some(direction)) => Some(direction * (last - entry) / entry),
+            _ => None,
+        };
+        true
+    }
+
+    fn resolve(&mut self, status: SignalLifecycle, realized_return: f64, now: u64) {
+        self.status = status;
+        self.realized_return = Some(realized_return);
+        self.resolved_at = Some(now);
+    }
+
+    pub fn horizon_return(&self, horizon: OutcomeHorizon) -> Option<f64> {
+        match horizon {
+            OutcomeHorizon::OneHour => self.return_1h,
+            OutcomeHorizon::OneDay => self.return_24h,
+            OutcomeHorizon::OneWeek => self.return_7d,
+        }
+    }
+
+    pub fn set_horizon_return(&mut self, horizon: OutcomeHorizon, value: f64) {
+        match horizon {
+            OutcomeHorizon::OneHour => self.return_1h = Some(value),
+            OutcomeHorizon::OneDay => self.return_24h = Some(value),
+            OutcomeHorizon::OneWeek => self.return_7d = Some(value),
+        }
+    }
+}
+
+/// Signal feed entry
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SignalWithOutcome {
+    pub signal: Signal,
+    pub outcome: Option<SignalOutcome>,
+}
+
+/// Aggregate results at one horizon
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HorizonStats {
+    pub evaluations: u64,
+    pub profitable: u64,
+    pub total_return: f64,
+}
+
+impl HorizonStats {
+    pub fn average_return(&self) -> f64 {
+        if self.evaluations == 0 {
+            0.0
+        } else {
+            self.total_return / self.evaluations as f64
+        }
+    }
+}
+
+/// Influencer registry entry with reputation derived from realized outcomes
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct InfluencerStats {
+    pub influencer: String,
+    pub total_signals: u64,
+    pub last_signal_at: u64,
+    pub one_hour: HorizonStats,
+    pub one_day: HorizonStats,
+    pub one_week: HorizonStats,
+    pub take_profit_hits: u64,
+    pub stop_loss_hits: u64,
+    /// 0.0 - 1.0; 0.5 with no evaluated signals
+    pub reputation_score: f64,
+}
+
+impl InfluencerStats {
+    pub fn new(influencer: String) -> Self {
+        InfluencerStats {
+            influencer,
+            total_signals: 0,
+            last_signal_at: 0,
+            one_hour: HorizonStats::default(),
+            one_day: HorizonStats::default(),
+            one_week: HorizonStats::default(),
+            take_profit_hits: 0,
+            stop_loss_hits: 0,
+            reputation_score: 0.5,
+        }
+    }
+
+    pub fn horizon(&self, horizon: OutcomeHorizon) -> &HorizonStats {
+        match horizon {
+            OutcomeHorizon::OneHour => &self.one_hour,
+            OutcomeHorizon::OneDay => &self.one_day,
+            OutcomeHorizon::OneWeek => &self.one_week,
+        }
+    }
+
+    pub fn record_return(&mut self, horizon: OutcomeHorizon, directional_return: f64) {
+        let stats = match horizon {
+            OutcomeHorizon::OneHour => &mut self.one_hour,
+            OutcomeHorizon::OneDay => &mut self.one_day,
+            OutcomeHorizon::OneWeek => &mut self.one_week,
+        };
+        stats.evaluations += 1;
+        stats.total_return += directional_return;
+        if directional_return > 0.0 {
+            stats.profitable += 1;
+        }
+        self.update_score();
+    }
+
+    pub fn record_target_hit(&mut self, hit: TargetHit) {
+        match hit {
+            TargetHit::TakeProfit => self.take_profit_hits += 1,
+            TargetHit::StopLoss => self.stop_loss_hits += 1,
+        }
+        self.update_score();
+    }
+
+    /// Weighted share of profitable outcomes, shrunk towards 0.5 by
+    /// `REPUTATION_PRIOR_WEIGHT`
+    fn update_score(&mut self) {
+        let mut wins = REPUTATION_PRIOR_WEIGHT * 0.5;
+        let mut total = REPUTATION_PRIOR_WEIGHT;
+        for horizon in [OutcomeHorizon::OneHour, OutcomeHorizon::OneDay, OutcomeHorizon::OneWeek] {
+            let stats = self.horizon(horizon);
+            wins += horizon.weight() * stats.profitable as f64;
+            total += horizon.weight() * stats.evaluations as f64;
+        }
+        wins += TARGET_HIT_WEIGHT * self.take_profit_hits as f64;
+        total += TARGET_HIT_WEIGHT * (self.take_profit_hits + self.stop_loss_hits) as f64;
+        self.reputation_score = wins / total;
+    }
 }
 
 /// Microchain Profile
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct MicrochainProfile {
+    /// The wallet the profile was created for; it cannot be unlinked
     pub id: String,
     pub name: String,
+    /// Every wallet that resolves to this profile, `id` first
     pub wallets: Vec<String>,
     pub preferred_chains: Vec<String>,
-    pub visibility: String,
+    pub visibility: ProfileVisibility,
     pub created_at: u64,
+    /// Signer that created the profile; it and any linked wallet may manage it
+    pub owner: String,
+    pub updated_at: Option<u64>,
+    /// Expected in the next `WalletLinkProof`, so proofs cannot be replayed.
+    /// Creation uses the nonce before it, and a deleted profile's nonce
+    /// carries over if its id is created again.
+    pub link_nonce: u64,
+    /// The trader's own chain in registry mode, where the profile is managed
+    #[serde(default)]
+    pub chain_id: Option<ChainId>,
     // Performance tracking for leaderboard
     pub total_trades: u64,
     pub winning_trades: u64,
@@ -36,6 +612,112 @@ pub struct MicrochainProfile {
     pub total_pnl: i64, // Can be negative
 }
 
+impl MicrochainProfile {
+    pub fn is_managed_by(&self, signer: &str) -> bool {
+        self.owner == signer || self.wallets.iter().any(|wallet| wallet == signer)
+    }
+
+    /// Whether the profile is managed on `chain`. Profiles without a chain
+    /// of their own live wherever they are stored.
+    pub fn is_hosted_on(&self, chain: ChainId) -> bool {
+        self.chain_id.is_none_or(|hosted| hosted == chain)
+    }
+
+    /// This profile as seen by a viewer its visibility does not allow:
+    /// name and chains only, without linked wallets or trading stats
+    pub fn redacted(&self) -> MicrochainProfile {
+        MicrochainProfile {
+            wallets: Vec::new(),
+            owner: String::new(),
+            link_nonce: 0,
+            total_trades: 0,
+            winning_trades: 0,
+            total_volume: 0,
+            total_pnl: 0,
+            ..self.clone()
+        }
+    }
+}
+
+/// Message a wallet signs to prove it may be linked to a profile, or that
+/// a new profile may be created for it. The chain and application pin the
+/// proof to the one place it is checked, since a profile in registry mode
+/// also exists on its own chain.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WalletLinkProof {
+    pub chain_id: ChainId,
+    pub application_id: ApplicationId,
+    pub profile_id: String,
+    pub wallet: String,
+    pub nonce: u64,
+}
+
+impl BcsSignable<'_> for WalletLinkProof {}
+
+impl WalletLinkProof {
+    /// Whether `signature` is by `wallet` over this proof
+    pub fn is_signed_by_wallet(&self, signature: &AccountSignature) -> bool {
+        signature.owner().to_string() == self.wallet && signature.verify(self).is_ok()
+    }
+}
+
+/// Who the service shows a profile's wallets and stats to. Like
+/// `StrategyVisibility`, this is advisory: the viewer a query names is not
+/// authenticated, and profile data is stored in the clear on chain.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, Enum)]
+#[serde(try_from = "String", into = "String")]
+pub enum ProfileVisibility {
+    Public,
+    /// Visible to followers only ("gated" in older clients)
+    Followers,
+    Private,
+}
+
+impl ProfileVisibility {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ProfileVisibility::Public => "public",
+            ProfileVisibility::Followers => "followers",
+            ProfileVisibility::Private => "private",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "public" => Some(ProfileVisibility::Public),
+            "followers" | "gated" => Some(ProfileVisibility::Followers),
+            "private" => Some(ProfileVisibility::Private),
+            _ => None,
+        }
+    }
+}
+
+impl ProfileVisibility {
+    /// `is_follower`: the viewer follows a strategy owned by one of the
+    /// profile's wallets
+    pub fn allows(&self, is_manager: bool, is_follower: bool) -> bool {
+        match self {
+            ProfileVisibility::Public => true,
+            ProfileVisibility::Followers => is_manager || is_follower,
+            ProfileVisibility::Private => is_manager,
+        }
+    }
+}
+
+impl TryFrom<String> for ProfileVisibility {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        ProfileVisibility::parse(&value).ok_or_else(|| format!("unknown visibility {:?}", value))
+    }
+}
+
+impl From<ProfileVisibility> for String {
+    fn from(value: ProfileVisibility) -> Self {
+        value.as_str().to_string()
+    }
+}
+
 /// Leaderboard entry for network analytics
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct LeaderboardEntry {
@@ -46,6 +728,34 @@ pub struct LeaderboardEntry {
     pub trades: u64,
     pub volume: u64,
     pub chain: String,
+    pub risk: RiskMetrics,
+}
+
+/// Leaderboard ordering; every key ranks best first
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub enum LeaderboardSort {
+    Roi,
+    WinRate,
+    #[default]
+    Sharpe,
+    Sortino,
+    /// Shallowest drawdown first
+    MaxDrawdown,
+    ExposureAdjustedReturn,
+}
+
+impl LeaderboardSort {
+    /// Comparison value where higher ranks first
+    pub fn key(&self, risk: &RiskMetrics) -> f64 {
+        match self {
+            LeaderboardSort::Roi => risk.roi,
+            LeaderboardSort::WinRate => risk.win_rate,
+            LeaderboardSort::Sharpe => risk.sharpe_ratio,
+            LeaderboardSort::Sortino => risk.sortino_ratio,
+            LeaderboardSort::MaxDrawdown => -risk.max_drawdown,
+            LeaderboardSort::ExposureAdjustedReturn => risk.exposure_adjusted_return,
+        }
+    }
 }
 
 /// Network-wide analytics
@@ -63,7 +773,7 @@ pub struct NetworkAnalytics {
 // ============================================
 
 /// Source of the strategy
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub enum StrategySource {
     /// User-created strategy
     Manual { author: String },
@@ -83,8 +793,9 @@ pub struct Strategy {
     pub name: String,
     pub strategy_type: StrategyType,
     pub active: bool,
+    /// Set by the contract at creation
     pub created_at: u64,
-    // Phase 2: Versioning
+    // Phase 2: Versioning, maintained by the contract (v1 at creation)
     pub version: u64,
     pub updated_at: Option<u64>,
     // Phase 2: Source tracking
@@ -93,27 +804,366 @@ pub struct Strategy {
     pub risk_percentage: f64,       // Max % of portfolio to risk per trade
     pub max_exposure: f64,          // Max total exposure in USD
     pub slippage_bps: u16,          // Max slippage tolerance in basis points
+    // Fees charged to followers; clients that predate fees send none
+    #[serde(default)]
+    pub fees: StrategyFees,
+    /// Maintained by the contract; ignored on create and update
+    #[serde(default)]
+    pub follower_count: u64,
+    /// Version at which risk parameters last changed; maintained by the contract
+    #[serde(default)]
+    pub risk_version: u64,
+    /// Set by the contract when the strategy is a fork
+    #[serde(default)]
+    pub forked_from: Option<ForkOrigin>,
+    /// Maintained by the contract
+    #[serde(default)]
+    pub fork_count: u64,
+    /// Who the service shows `strategy_type` to; advisory, see
+    /// `StrategyVisibility`
+    #[serde(default)]
+    pub visibility: StrategyVisibility,
+    /// `rules_commitment` of the withheld rules when `strategy_type` is `Hidden`
+    #[serde(default)]
+    pub rules_commitment: Option<String>,
+}
+
+/// Who the service shows a strategy's rules to. Performance is public at
+/// every level.
+///
+/// This is advisory redaction, not access control: queries carry no caller
+/// identity, so the `viewer` a client names is taken on trust, and stored
+/// rules are readable by anyone running a node. Only `StrategyType::Hidden`
+/// with a rules commitment, revealed later through `RevealStrategyRules`,
+/// keeps rules secret.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub enum StrategyVisibility {
+    #[default]
+    Public,
+    /// The owner and current followers
+    FollowersOnly,
+    /// The owner only
+    Private,
 }
 
-/// Strategy version history entry
+impl StrategyVisibility {
+    pub fn allows(&self, is_owner: bool, is_follower: bool) -> bool {
+        match self {
+            StrategyVisibility::Public => true,
+            StrategyVisibility::FollowersOnly => is_owner || is_follower,
+            StrategyVisibility::Private => is_owner,
+        }
+    }
+}
+
+/// What a rules commitment hashes
+#[derive(Deserialize, Serialize)]
+pub struct RulesPreimage {
+    pub strategy_type: StrategyType,
+    pub salt: String,
+}
+
+impl BcsHashable<'_> for RulesPreimage {}
+
+/// Commitment to a strategy's rules, published in place of the rules and
+/// checked when they are revealed. The salt must stay secret until then.
+pub fn rules_commitment(strategy_type: &StrategyType, salt: &str) -> String {
+    let preimage = RulesPreimage { strategy_type: strategy_type.clone(), salt: salt.to_string() };
+    CryptoHash::new(&preimage).to_string()
+}
+
+/// Rules of a committed strategy version, proven against its commitment
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RulesReveal {
+    pub strategy_id: u64,
+    pub version: u64,
+    pub strategy_type: StrategyType,
+    pub salt: String,
+    pub revealed_by: String,
+    pub revealed_at: u64,
+}
+
+/// Curator ratings run from 1 to 5
+pub const MIN_CURATOR_RATING: u8 = 1;
+pub const MAX_CURATOR_RATING: u8 = 5;
+/// Longest review text a curator may store
+pub const MAX_REVIEW_LEN: usize = 1_000;
+
+/// A curator's rating of a strategy
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CuratorReview {
+    pub strategy_id: u64,
+    pub curator: String,
+    pub rating: u8,
+    pub review: String,
+    /// Strategy version the rating was given for
+    pub strategy_version: u64,
+    pub reviewed_at: u64,
+}
+
+pub fn is_valid_review(rating: u8, review: &str) -> bool {
+    (MIN_CURATOR_RATING..=MAX_CURATOR_RATING).contains(&rating) && review.len() <= MAX_REVIEW_LEN
+}
+
+/// Aggregate of the current curator ratings of a strategy
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct RatingSummary {
+    pub rating_count: u64,
+    pub rating_sum: u64,
+}
+
+impl RatingSummary {
+    pub fn add(&mut self, rating: u8) {
+        self.rating_count += 1;
+        self.rating_sum += u64::from(rating);
+    }
+
+    pub fn remove(&mut self, rating: u8) {
+        self.rating_count = self.rating_count.saturating_sub(1);
+        self.rating_sum = self.rating_sum.saturating_sub(u64::from(rating));
+    }
+
+    /// Mean rating, 0 when unrated
+    pub fn average(&self) -> f64 {
+        if self.rating_count == 0 {
+            return 0.0;
+        }
+        self.rating_sum as f64 / self.rating_count as f64
+    }
+}
+
+/// A strategy with at least one curator rating
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CuratedStrategy {
+    pub strategy: Strategy,
+    pub ratings: RatingSummary,
+    pub average_rating: f64,
+}
+
+/// The strategy and version a fork was copied from
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ForkOrigin {
+    pub strategy_id: u64,
+    pub version: u64,
+}
+
+/// Fields a forker may change while copying a strategy; unset fields are copied
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[serde(default)]
+pub struct StrategyOverrides {
+    pub name: Option<String>,
+    pub strategy_type: Option<StrategyType>,
+    pub risk_percentage: Option<f64>,
+    pub max_exposure: Option<f64>,
+    pub slippage_bps: Option<u16>,
+    pub fees: Option<StrategyFees>,
+}
+
+/// A strategy's place in a fork tree
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ForkNode {
+    pub strategy_id: u64,
+    pub owner: String,
+    pub name: String,
+    pub forked_from: Option<ForkOrigin>,
+    pub fork_count: u64,
+    /// Distance from the queried strategy; ancestors are negative
+    pub depth: i32,
+}
+
+impl ForkNode {
+    pub fn new(strategy: &Strategy, depth: i32) -> Self {
+        Self {
+            strategy_id: strategy.id,
+            owner: strategy.owner.clone(),
+            name: strategy.name.clone(),
+            forked_from: strategy.forked_from.clone(),
+            fork_count: strategy.fork_count,
+            depth,
+        }
+    }
+}
+
+/// A strategy with the strategies it descends from and its forks
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ForkTree {
+    /// Root first
+    pub ancestors: Vec<ForkNode>,
+    pub strategy: ForkNode,
+    /// Breadth first
+    pub descendants: Vec<ForkNode>,
+}
+
+/// One field that differs between two strategy versions
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct FieldChange {
+    pub field: String,
+    pub from: String,
+    pub to: String,
+    /// Whether the field is a risk parameter
+    pub risk: bool,
+}
+
+/// Field-level changes from one strategy version to another
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct StrategyDiff {
+    pub strategy_id: u64,
+    pub from_version: u64,
+    pub to_version: u64,
+    pub changes: Vec<FieldChange>,
+}
+
+impl StrategyDiff {
+    /// Keep which rule fields changed but not their values
+    pub fn redacted(mut self) -> Self {
+        for change in &mut self.changes {
+            if change.field == "strategy_type" || change.field.starts_with("form.") {
+                change.from = "hidden".to_string();
+                change.to = "hidden".to_string();
+            }
+        }
+        self
+    }
+}
+
+fn push_change<T: PartialEq + std::fmt::Debug>(
+    changes: &mut Vec<FieldChange>,
+    field: &str,
+    from: &T,
+    to: &T,
+    risk: bool,
+) {
+    if from != to {
+        changes.push(FieldChange {
+            field: field.to_string(),
+            from: format!("{:?}", from),
+            to: format!("{:?}", to),
+            risk,
+        });
+    }
+}
+
+impl Strategy {
+    /// Hidden rules need a commitment, and a commitment only makes sense
+    /// for hidden rules
+    pub fn rules_are_valid(&self) -> bool {
+        (self.strategy_type == StrategyType::Hidden) == self.rules_commitment.is_some()
+    }
+
+    /// This strategy as seen by a viewer who may not read its rules
+    pub fn redacted(&self) -> Strategy {
+        Strategy { strategy_type: StrategyType::Hidden, ..self.clone() }
+    }
+
+    /// A copy owned by `owner` with provenance pointing at this version.
+    /// Forks start inactive with no fees unless overridden.
+    pub fn fork(&self, owner: String, overrides: StrategyOverrides) -> Strategy {
+        Strategy {
+            id: 0,
+            name: overrides.name.unwrap_or_else(|| format!("{} (fork)", self.name)),
+            strategy_type: overrides.strategy_type.unwrap_or_else(|| self.strategy_type.clone()),
+            active: false,
+            source: StrategySource::Manual { author: owner.clone() },
+            owner,
+            risk_percentage: overrides.risk_percentage.unwrap_or(self.risk_percentage),
+            max_exposure: overrides.max_exposure.unwrap_or(self.max_exposure),
+            slippage_bps: overrides.slippage_bps.unwrap_or(self.slippage_bps),
+            fees: overrides.fees.unwrap_or_default(),
+            follower_count: 0,
+            forked_from: Some(ForkOrigin { strategy_id: self.id, version: self.version }),
+            fork_count: 0,
+            ..self.clone()
+        }
+    }
+
+    /// `proposed` as the version after this one. Identity, provenance and
+    /// contract-maintained fields are kept from `self` whatever the caller sent.
+    pub fn next_version(&self, proposed: Strategy, now: u64) -> Strategy {
+        let mut next = Strategy {
+            id: self.id,
+            owner: self.owner.clone(),
+            created_at: self.created_at,
+            source: self.source.clone(),
+            version: self.version + 1,
+            updated_at: Some(now),
+            follower_count: self.follower_count,
+            risk_version: self.risk_version,
+            forked_from: self.forked_from.clone(),
+            fork_count: self.fork_count,
+            ..proposed
+        };
+        if self.risk_changed(&next) {
+            next.risk_version = next.version;
+        }
+        next
+    }
+
+    /// Rule, risk and fee fields that differ in `other`. Identity and
+    /// contract-maintained fields (id, owner, version, counts) are ignored.
+    pub fn diff(&self, other: &Strategy) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+        push_change(&mut changes, "name", &self.name, &other.name, false);
+        push_change(&mut changes, "active", &self.active, &other.active, false);
+        push_change(&mut changes, "source", &self.source, &other.source, false);
+        push_change(&mut changes, "visibility", &self.visibility, &other.visibility, false);
+        push_change(&mut changes, "rules_commitment", &self.rules_commitment, &other.rules_commitment, true);
+        match (&self.strategy_type, &other.strategy_type) {
+            (StrategyType::Form(from), StrategyType::Form(to)) => {
+                push_change(&mut changes, "form.token_pair", &from.token_pair, &to.token_pair, true);
+                push_change(&mut changes, "form.buy_price", &from.buy_price, &to.buy_price, false);
+                push_change(&mut changes, "form.sell_target", &from.sell_target, &to.sell_target, false);
+                push_change(&mut changes, "form.trailing_stop_pct", &from.trailing_stop_pct, &to.trailing_stop_pct, true);
+                push_change(&mut changes, "form.take_profit_pct", &from.take_profit_pct, &to.take_profit_pct, false);
+                push_change(&mut changes, "form.max_loss_pct", &from.max_loss_pct, &to.max_loss_pct, true);
+            }
+            // DSL rules cannot be compared field by field
+            (from, to) => push_change(&mut changes, "strategy_type", from, to, true),
+        }
+        push_change(&mut changes, "risk_percentage", &self.risk_percentage, &other.risk_percentage, true);
+        push_change(&mut changes, "max_exposure", &self.max_exposure, &other.max_exposure, true);
+        push_change(&mut changes, "slippage_bps", &self.slippage_bps, &other.slippage_bps, true);
+        push_change(&mut changes, "fees.performance_fee_bps", &self.fees.performance_fee_bps, &other.fees.performance_fee_bps, false);
+        push_change(&mut changes, "fees.subscription_fee", &self.fees.subscription_fee, &other.fees.subscription_fee, false);
+        push_change(
+            &mut changes,
+            "fees.subscription_period_micros",
+            &self.fees.subscription_period_micros,
+            &other.fees.subscription_period_micros,
+            false,
+        );
+        changes
+    }
+
+    /// Whether moving to `other` changes any risk parameter
+    pub fn risk_changed(&self, other: &Strategy) -> bool {
+        self.diff(other).iter().any(|change| change.risk)
+    }
+}
+
+/// Strategy version history entry, one per version including the current one
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct StrategyVersion {
     pub strategy_id: u64,
     pub version: u64,
+    /// The strategy as of this version
     pub strategy_snapshot: Strategy,
+    /// When this version took effect
     pub changed_at: u64,
+    /// Why this version was made
     pub change_reason: Option<String>,
 }
 
 /// Strategy type: Form-based or DSL code
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub enum StrategyType {
     Form(FormStrategy),
     DSL(String),
+    /// Rules withheld: committed to on chain, or redacted for the viewer
+    Hidden,
 }
 
 /// Form-based strategy parameters
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub struct FormStrategy {
     pub token_pair: String,
     pub buy_price: f64,
@@ -123,14 +1173,183 @@ pub struct FormStrategy {
     pub max_loss_pct: f64,
 }
 
+/// Minimum signal confidence for a form strategy to take a trade in the
+/// backtester; the contract does not apply it
+pub const FORM_MIN_SIGNAL_CONFIDENCE: f64 = 0.7;
+
+impl FormStrategy {
+    /// Base token of the pair, e.g. "SOL" for "SOL/USDC" or "SOL-USDC"
+    pub fn base_token(&self) -> &str {
+        self.token_pair.split(['/', '-']).next().unwrap_or("").trim()
+    }
+
+    /// Whether a signal opens a long under this strategy at `price`: the
+    /// token matches, the call is bullish with enough confidence, and price
+    /// is at or below `buy_price` when one is set. Entry rule of the native
+    /// backtester; on chain, orders are created by `CreateOrder` callers.
+    pub fn accepts_signal(&self, signal: &Signal, price: f64) -> bool {
+        signal.token.eq_ignore_ascii_case(self.base_token())
+            && signal.sentiment == Sentiment::Bullish
+            && signal.confidence >= FORM_MIN_SIGNAL_CONFIDENCE
+            && (self.buy_price <= 0.0 || price <= self.buy_price)
+    }
+}
+
 /// Order status
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub enum OrderStatus {
     Pending,
     Submitted,
     Filled,
     Failed,
     Cancelled,
+    Expired,
+}
+
+impl OrderStatus {
+    /// Whether the order can still be filled, cancelled or expired
+    pub fn is_open(&self) -> bool {
+        matches!(self, OrderStatus::Pending | OrderStatus::Submitted)
+    }
+}
+
+/// Default time an IOC or FOK order stays live unfilled (micros); the
+/// admin can change it with `SetImmediateOrderWindow`
+pub const DEFAULT_IMMEDIATE_ORDER_WINDOW_MICROS: u64 = 60_000_000;
+
+/// Time in force for orders.
+///
+/// The chain only records fills reported after the fact, so IOC and FOK
+/// are approximated by a short expiry window rather than enforced at the
+/// venue. Fills are recorded whole, except for the slices of TWAP, VWAP
+/// and iceberg orders, which fill-or-kill orders may not use; a FOK order
+/// therefore fills completely or not at all.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub enum TimeInForce {
+    /// Good till cancelled
+    #[default]
+    GoodTillCancelled,
+    /// Good till the given time (micros)
+    GoodTillTime { expires_at: u64 },
+    /// Immediate or cancel: whatever is unfilled after the immediate
+    /// window expires; partial fills are kept
+    ImmediateOrCancel,
+    /// Fill or kill: must fill completely within the immediate window
+    FillOrKill,
+}
+
+impl TimeInForce {
+    /// Expiry time for an order created at `created_at`, if any, with IOC
+    /// and FOK orders live for `immediate_window` micros
+    pub fn expires_at(&self, created_at: u64, immediate_window: u64) -> Option<u64> {
+        match self {
+            TimeInForce::GoodTillCancelled => None,
+            TimeInForce::GoodTillTime { expires_at } => Some(*expires_at),
+            TimeInForce::ImmediateOrCancel | TimeInForce::FillOrKill => {
+                Some(created_at.saturating_add(immediate_window))
+            }
+        }
+    }
+
+    /// The same time in force for a copy that starts at `start` instead of
+    /// `created_at`: a good-till-time deadline keeps its lifetime
+    pub fn delayed(&self, created_at: u64, start: u64) -> TimeInForce {
+        match self {
+            TimeInForce::GoodTillTime { expires_at } => TimeInForce::GoodTillTime {
+                expires_at: start.saturating_add(expires_at.saturating_sub(created_at)),
+            },
+            other => other.clone(),
+        }
+    }
+}
+
+/// Whether an order with the given expiry and status has expired at `now`
+pub fn is_expired(expires_at: Option<u64>, status: &OrderStatus, now: u64) -> bool {
+    status.is_open() && expires_at.is_some_and(|expires_at| now >= expires_at)
+}
+
+/// Kind of trading order
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq, Enum)]
+#[serde(try_from = "String", into = "String")]
+pub enum OrderType {
+    Buy,
+    Sell,
+    Market,
+    Limit,
+    /// Protective exits; the suffix is the side of the exit trade
+    StopLossSell,
+    StopLossBuy,
+    TakeProfitSell,
+    TakeProfitBuy,
+}
+
+impl OrderType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderType::Buy => "buy",
+            OrderType::Sell => "sell",
+            OrderType::Market => "market",
+            OrderType::Limit => "limit",
+            OrderType::StopLossSell => "stop_loss_sell",
+            OrderType::StopLossBuy => "stop_loss_buy",
+            OrderType::TakeProfitSell => "take_profit_sell",
+            OrderType::TakeProfitBuy => "take_profit_buy",
+        }
+    }
+
+    /// Parse order types, accepting the legacy upper-case database values;
+    /// a bare "stop_loss" or "take_profit" protects a long position
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "buy" => Some(OrderType::Buy),
+            "sell" => Some(OrderType::Sell),
+            "market" => Some(OrderType::Market),
+            "limit" => Some(OrderType::Limit),
+            "stop_loss" | "stop_loss_sell" => Some(OrderType::StopLossSell),
+            "stop_loss_buy" => Some(OrderType::StopLossBuy),
+            "take_profit" | "take_profit_sell" => Some(OrderType::TakeProfitSell),
+            "take_profit_buy" => Some(OrderType::TakeProfitBuy),
+            _ => None,
+        }
+    }
+
+    /// The opposite side. A long's stop-loss mirrors a short's take-profit,
+    /// since both fire as price falls. Market and limit orders carry no side.
+    pub fn reversed(&self) -> Option<Self> {
+        match self {
+            OrderType::Buy => Some(OrderType::Sell),
+            OrderType::Sell => Some(OrderType::Buy),
+            OrderType::StopLossSell => Some(OrderType::TakeProfitBuy),
+            OrderType::TakeProfitBuy => Some(OrderType::StopLossSell),
+            OrderType::TakeProfitSell => Some(OrderType::StopLossBuy),
+            OrderType::StopLossBuy => Some(OrderType::TakeProfitSell),
+            OrderType::Market | OrderType::Limit => None,
+        }
+    }
+
+    /// Protective exit order type for a bracket leg
+    pub fn bracket_exit(leg: &BracketLeg, entry_is_short: bool) -> Self {
+        match (leg, entry_is_short) {
+            (BracketLeg::StopLoss, false) => OrderType::StopLossSell,
+            (BracketLeg::StopLoss, true) => OrderType::StopLossBuy,
+            (BracketLeg::TakeProfit, false) => OrderType::TakeProfitSell,
+            (BracketLeg::TakeProfit, true) => OrderType::TakeProfitBuy,
+        }
+    }
+}
+
+impl TryFrom<String> for OrderType {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        OrderType::parse(&value).ok_or_else(|| format!("unknown order type {:?}", value))
+    }
+}
+
+impl From<OrderType> for String {
+    fn from(value: OrderType) -> Self {
+        value.as_str().to_string()
+    }
 }
 
 /// Trading order
@@ -139,7 +1358,7 @@ pub struct Order {
     pub id: u64,
     pub strategy_id: u64,
     pub signal_id: u64,
-    pub order_type: String,
+    pub order_type: OrderType,
     pub token: String,
     pub quantity: f64,
     pub status: OrderStatus,
@@ -147,6 +1366,176 @@ pub struct Order {
     pub fill_price: Option<f64>,
     pub created_at: u64,
     pub filled_at: Option<u64>,
+    // Time in force; expiry is computed by the contract at creation
+    #[serde(default)]
+    pub time_in_force: TimeInForce,
+    #[serde(default)]
+    pub expires_at: Option<u64>,
+    // Bracket orders: protective exits requested on an entry order
+    pub bracket: Option<BracketSpec>,
+    // Set on bracket children: the entry order they protect
+    pub parent_order_id: Option<u64>,
+    pub trigger_price: Option<f64>,
+}
+
+/// Protective stop-loss and take-profit prices attached to an entry order
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BracketSpec {
+    pub stop_loss: f64,
+    pub take_profit: f64,
+}
+
+impl BracketSpec {
+    /// Bracket from a signal's stop-loss and take-profit, if it has both
+    pub fn from_signal(signal: &Signal) -> Option<Self> {
+        Some(BracketSpec {
+            stop_loss: signal.stop_loss?,
+            take_profit: signal.take_profit?,
+        })
+    }
+
+    /// Bracket for a long entry at `entry_price` using a form strategy's
+    /// `max_loss_pct` and `take_profit_pct`
+    pub fn from_form(form: &FormStrategy, entry_price: f64) -> Self {
+        BracketSpec {
+            stop_loss: entry_price * (1.0 - form.max_loss_pct / 100.0),
+            take_profit: entry_price * (1.0 + form.take_profit_pct / 100.0),
+        }
+    }
+
+    /// Prices must be positive and on the protective side for the entry:
+    /// stop below take-profit for longs, above it for shorts
+    pub fn is_valid(&self, is_short: bool) -> bool {
+        if !(self.stop_loss > 0.0 && self.take_profit > 0.0) {
+            return false;
+        }
+        if is_short {
+            self.stop_loss > self.take_profit
+        } else {
+            self.stop_loss < self.take_profit
+        }
+    }
+}
+
+impl Order {
+    /// Whether this order opens or adds to a short position
+    pub fn is_short(&self) -> bool {
+        self.order_type == OrderType::Sell
+    }
+
+    /// Whether this order closes a position: a bracket leg or a protective
+    /// stop-loss or take-profit order
+    pub fn is_exit(&self) -> bool {
+        self.parent_order_id.is_some()
+            || matches!(
+                self.order_type,
+                OrderType::StopLossSell | OrderType::StopLossBuy | OrderType::TakeProfitSell | OrderType::TakeProfitBuy
+            )
+    }
+
+    /// The mirror-image order on the opposite side, with bracket prices
+    /// swapped so the stop still protects the position
+    pub fn reversed(&self) -> Option<Order> {
+        let mut reversed = self.clone();
+        reversed.order_type = self.order_type.reversed()?;
+        reversed.bracket = self.bracket.as_ref().map(|spec| BracketSpec {
+            stop_loss: spec.take_profit,
+            take_profit: spec.stop_loss,
+        });
+        Some(reversed)
+    }
+
+    /// Whether a fill may still be recorded. Only open orders fill, so a
+    /// cancelled bracket sibling or a skipped copy stays unfilled.
+    pub fn can_fill(&self) -> bool {
+        self.status.is_open()
+    }
+
+    pub fn apply_fill(&mut self, tx_hash: String, fill_price: f64, filled_at: u64) {
+        self.status = OrderStatus::Filled;
+        self.tx_hash = Some(tx_hash);
+        self.fill_price = Some(fill_price);
+        self.filled_at = Some(filled_at);
+    }
+
+    /// Whether `price` reaches this order's trigger: stops fire when price
+    /// moves against the position, take-profits when it moves in favour.
+    /// Orders without a trigger are never triggered. Only the backtester
+    /// fills on this; on chain, legs fill on `RecordOrderFill`.
+    pub fn is_triggered_at(&self, price: f64) -> bool {
+        let Some(trigger) = self.trigger_price else {
+            return false;
+        };
+        match self.order_type {
+            OrderType::StopLossSell | OrderType::TakeProfitBuy => price <= trigger,
+            OrderType::StopLossBuy | OrderType::TakeProfitSell => price >= trigger,
+            _ => false,
+        }
+    }
+
+    /// Protective exit order for one leg of this entry's bracket
+    pub fn bracket_child(&self, id: u64, leg: BracketLeg, trigger_price: f64, now: u64) -> Order {
+        Order {
+            id,
+            strategy_id: self.strategy_id,
+            signal_id: self.signal_id,
+            order_type: OrderType::bracket_exit(&leg, self.is_short()),
+            token: self.token.clone(),
+            quantity: self.quantity,
+            status: OrderStatus::Pending,
+            tx_hash: None,
+            fill_price: None,
+            created_at: now,
+            filled_at: None,
+            time_in_force: TimeInForce::GoodTillCancelled,
+            expires_at: None,
+            bracket: None,
+            parent_order_id: Some(self.id),
+            trigger_price: Some(trigger_price),
+        }
+    }
+}
+
+/// Which protective leg of a bracket an order is
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub enum BracketLeg {
+    StopLoss,
+    TakeProfit,
+}
+
+/// Bracket lifecycle
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub enum BracketStatus {
+    /// Both protective orders are live
+    Active,
+    /// One leg filled and the other was cancelled
+    Triggered { leg: BracketLeg },
+    /// Position closed manually; both legs cancelled
+    Closed,
+}
+
+/// Linked one-cancels-other protective orders for a filled entry order
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Bracket {
+    pub entry_order_id: u64,
+    pub stop_loss_order_id: u64,
+    pub take_profit_order_id: u64,
+    pub status: BracketStatus,
+    pub created_at: u64,
+    pub closed_at: Option<u64>,
+}
+
+impl Bracket {
+    /// The leg and sibling of one of this bracket's child orders
+    pub fn leg_of(&self, order_id: u64) -> Option<(BracketLeg, u64)> {
+        if order_id == self.stop_loss_order_id {
+            Some((BracketLeg::StopLoss, self.take_profit_order_id))
+        } else if order_id == self.take_profit_order_id {
+            Some((BracketLeg::TakeProfit, self.stop_loss_order_id))
+        } else {
+            None
+        }
+    }
 }
 
 /// DEX types
@@ -226,6 +1615,147 @@ pub struct DEXOrder {
     // Phase 3: Conditional execution
     pub conditional_trigger: Option<ConditionalTrigger>,
     pub execution_mode: ExecutionMode,
+    // Split routing: weighted parallel legs (empty = single route)
+    #[serde(default)]
+    pub split_legs: Vec<RouteLeg>,
+    // Sliced execution (TWAP/VWAP/iceberg): child slices, planned by the contract
+    #[serde(default)]
+    pub slices: Vec<OrderSlice>,
+    #[serde(default)]
+    pub paused: bool,
+    // Time in force; expiry is computed by the contract at creation
+    #[serde(default)]
+    pub time_in_force: TimeInForce,
+    #[serde(default)]
+    pub expires_at: Option<u64>,
+}
+
+/// Total weight of a split route, in basis points
+pub const SPLIT_ROUTE_TOTAL_BPS: u16 = 10_000;
+
+/// One weighted leg of a split route, executed in parallel with the others
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RouteLeg {
+    /// Share of the order's input routed through this leg (basis points)
+    pub weight_bps: u16,
+    pub hops: Vec<RouteHop>,
+    /// Filled in on execution
+    pub tx_signature: Option<String>,
+    pub output_amount: Option<u64>,
+}
+
+impl RouteLeg {
+    /// Input amount routed through this leg for a given order input
+    pub fn input_amount(&self, order_input: u64) -> u64 {
+        (order_input as u128 * self.weight_bps as u128 / SPLIT_ROUTE_TOTAL_BPS as u128) as u64
+    }
+
+    /// Expected output of this leg: the expected output of its last hop
+    pub fn expected_output(&self) -> u64 {
+        self.hops.last().map(|hop| hop.expected_output).unwrap_or(0)
+    }
+}
+
+/// Per-leg execution report submitted when a split-route order is filled
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LegFill {
+    pub leg_index: usize,
+    pub tx_signature: String,
+    pub output_amount: u64,
+}
+
+/// Reasons a split route is rejected
+#[derive(Clone, Debug, Error, PartialEq, Deserialize, Serialize)]
+pub enum RouteError {
+    #[error("split route has no legs")]
+    NoLegs,
+    #[error("leg {0} has no hops")]
+    EmptyLeg(usize),
+    #[error("leg {0} has zero weight")]
+    ZeroWeight(usize),
+    #[error("leg weights sum to {0} bps, expected 10000")]
+    WeightsDoNotSum(u32),
+    #[error("leg {0} does not start at the order's input mint")]
+    WrongInputMint(usize),
+    #[error("leg {0} does not end at the order's output mint")]
+    WrongOutputMint(usize),
+    #[error("leg {0} has disconnected hops")]
+    DisconnectedHops(usize),
+    #[error("fill reported for unknown leg {0}")]
+    UnknownLeg(usize),
+    #[error("leg {0} reported more than once")]
+    DuplicateLegFill(usize),
+    #[error("{0} legs were not reported")]
+    MissingLegFills(usize),
+}
+
+impl DEXOrder {
+    /// Whether this order divides its input across parallel legs
+    pub fn is_split(&self) -> bool {
+        !self.split_legs.is_empty()
+    }
+
+    /// Check that every leg is a connected path from `input_mint` to
+    /// `output_mint` and that the leg weights cover the whole input.
+    pub fn validate_split_route(&self) -> Result<(), RouteError> {
+        if self.split_legs.is_empty() {
+            return Err(RouteError::NoLegs);
+        }
+
+        let mut total_bps: u32 = 0;
+        for (index, leg) in self.split_legs.iter().enumerate() {
+            let (Some(first), Some(last)) = (leg.hops.first(), leg.hops.last()) else {
+                return Err(RouteError::EmptyLeg(index));
+            };
+            if leg.weight_bps == 0 {
+                return Err(RouteError::ZeroWeight(index));
+            }
+            if first.input_mint != self.input_mint {
+                return Err(RouteError::WrongInputMint(index));
+            }
+            if last.output_mint != self.output_mint {
+                return Err(RouteError::WrongOutputMint(index));
+            }
+            if leg.hops.windows(2).any(|pair| pair[0].output_mint != pair[1].input_mint) {
+                return Err(RouteError::DisconnectedHops(index));
+            }
+            total_bps += leg.weight_bps as u32;
+        }
+
+        if total_bps != SPLIT_ROUTE_TOTAL_BPS as u32 {
+            return Err(RouteError::WeightsDoNotSum(total_bps));
+        }
+        Ok(())
+    }
+
+    /// Aggregate expected output across all legs
+    pub fn expected_split_output(&self) -> u64 {
+        self.split_legs.iter().map(RouteLeg::expected_output).sum()
+    }
+
+    /// Record per-leg fills, requiring exactly one report per leg.
+    /// Returns the aggregate output amount.
+    pub fn apply_leg_fills(&mut self, fills: &[LegFill]) -> Result<u64, RouteError> {
+        let mut seen = vec![false; self.split_legs.len()];
+        for fill in fills {
+            match seen.get_mut(fill.leg_index) {
+                None => return Err(RouteError::UnknownLeg(fill.leg_index)),
+                Some(true) => return Err(RouteError::DuplicateLegFill(fill.leg_index)),
+                Some(flag) => *flag = true,
+            }
+        }
+        let missing = seen.iter().filter(|reported| !**reported).count();
+        if missing > 0 {
+            return Err(RouteError::MissingLegFills(missing));
+        }
+
+        for fill in fills {
+            let leg = &mut self.split_legs[fill.leg_index];
+            leg.tx_signature = Some(fill.tx_signature.clone());
+            leg.output_amount = Some(fill.output_amount);
+        }
+        Ok(fills.iter().map(|fill| fill.output_amount).sum())
+    }
 }
 
 /// Execution mode for orders
@@ -237,6 +1767,155 @@ pub enum ExecutionMode {
     Conditional,
     /// Execute at scheduled time
     Scheduled { execute_at: u64 },
+    /// Split into equal slices executed `interval` microseconds apart
+    Twap { slices: u32, interval: u64 },
+    /// Split following a volume profile (one weight per slice), executed
+    /// `interval` microseconds apart
+    Vwap { volume_profile: Vec<u32>, interval: u64 },
+    /// Expose only `visible_amount` at a time; the next slice is released
+    /// once the previous one fills
+    Iceberg { visible_amount: u64 },
+}
+
+/// Upper bound on child slices per parent order
+pub const MAX_ORDER_SLICES: usize = 500;
+
+impl ExecutionMode {
+    /// Whether orders in this mode are executed as child slices
+    pub fn is_sliced(&self) -> bool {
+        matches!(
+            self,
+            ExecutionMode::Twap { .. } | ExecutionMode::Vwap { .. } | ExecutionMode::Iceberg { .. }
+        )
+    }
+
+    /// Plan the child slices for a parent order of `input_amount` starting at
+    /// `start`. Returns `None` if the mode parameters are invalid, would
+    /// produce more than `MAX_ORDER_SLICES` slices or an empty slice, or
+    /// schedule a slice past the end of time.
+    pub fn plan_slices(&self, input_amount: u64, start: u64) -> Option<Vec<OrderSlice>> {
+        let amounts: Vec<u64> = match self {
+            ExecutionMode::Twap { slices, .. } => {
+                let count = *slices as u64;
+                if count == 0 || count > MAX_ORDER_SLICES as u64 || count > input_amount {
+                    return None;
+                }
+                let mut amounts = vec![input_amount / count; count as usize];
+                *amounts.last_mut()? += input_amount % count;
+                amounts
+            }
+            ExecutionMode::Vwap { volume_profile, .. } => {
+                let total_weight: u64 = volume_profile.iter().map(|w| *w as u64).sum();
+                if volume_profile.contains(&0) || total_weight == 0 || volume_profile.len() > MAX_ORDER_SLICES {
+                    return None;
+                }
+                let mut amounts: Vec<u64> = volume_profile
+                    .iter()
+                    .map(|w| (input_amount as u128 * *w as u128 / total_weight as u128) as u64)
+                    .collect();
+                let allocated: u64 = amounts.iter().sum();
+                *amounts.last_mut()? += input_amount - allocated;
+                amounts
+            }
+            ExecutionMode::Iceberg { visible_amount } => {
+                if *visible_amount == 0 {
+                    return None;
+                }
+                let count = input_amount.div_ceil(*visible_amount);
+                if count == 0 || count > MAX_ORDER_SLICES as u64 {
+                    return None;
+                }
+                let mut amounts = vec![*visible_amount; count as usize];
+                *amounts.last_mut()? = input_amount - visible_amount * (count - 1);
+                amounts
+            }
+            _ => return None,
+        };
+        if amounts.contains(&0) {
+            return None;
+        }
+
+        let interval = match self {
+            ExecutionMode::Twap { interval, .. } | ExecutionMode::Vwap { interval, .. } => *interval,
+            _ => 0,
+        };
+
+        amounts
+            .into_iter()
+            .enumerate()
+            .map(|(index, input_amount)| {
+                let due_at = interval.checked_mul(index as u64)?.checked_add(start)?;
+                Some(OrderSlice {
+                    index: index as u32,
+                    input_amount,
+                    due_at,
+                    status: OrderStatus::Pending,
+                    tx_signature: None,
+                    output_amount: None,
+                    executed_at: None,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Child slice of a TWAP, VWAP or iceberg parent order
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OrderSlice {
+    pub index: u32,
+    pub input_amount: u64,
+    /// Earliest time (micros) the slice may execute
+    pub due_at: u64,
+    pub status: OrderStatus,
+    pub tx_signature: Option<String>,
+    pub output_amount: Option<u64>,
+    pub executed_at: Option<u64>,
+}
+
+/// Slice that a keeper should execute now
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DueSlice {
+    pub order_id: u64,
+    pub slice_index: u32,
+    pub input_mint: String,
+    pub output_mint: String,
+    pub input_amount: u64,
+    pub slippage_bps: u16,
+    pub due_at: u64,
+}
+
+impl DEXOrder {
+    /// Whether this order is executed as child slices
+    pub fn is_sliced(&self) -> bool {
+        !self.slices.is_empty()
+    }
+
+    /// The slice due for execution at `now`, if any. Slices execute one at a
+    /// time and in order, and nothing is due while the parent is paused or
+    /// no longer open.
+    pub fn due_slice(&self, now: u64) -> Option<&OrderSlice> {
+        if self.paused || !self.status.is_open() || is_expired(self.expires_at, &self.status, now) {
+            return None;
+        }
+        self.slices
+            .iter()
+            .find(|slice| matches!(slice.status, OrderStatus::Pending))
+            .filter(|slice| slice.due_at <= now)
+    }
+
+    /// Input amount filled so far across child slices
+    pub fn filled_input(&self) -> u64 {
+        self.slices
+            .iter()
+            .filter(|slice| matches!(slice.status, OrderStatus::Filled))
+            .map(|slice| slice.input_amount)
+            .sum()
+    }
+
+    /// Output amount received so far across child slices
+    pub fn filled_output(&self) -> u64 {
+        self.slices.iter().filter_map(|slice| slice.output_amount).sum()
+    }
 }
 
 /// Strategy Follower
@@ -248,25 +1927,298 @@ pub struct StrategyFollower {
     pub max_position_size: f64,
     pub auto_follow: bool,
     pub followed_at: u64,
+    pub policy: ReplicationPolicy,
+    // Lifecycle
+    pub paused: bool,
+    pub updated_at: Option<u64>,
+    // Copy statistics since following
+    pub copied_trades: u64,
+    pub skipped_copies: u64,
+    pub closed_trades: u64,
+    pub realized_pnl: f64,
+    /// Strategy version the follower last agreed to
+    pub consented_version: u64,
 }
 
-/// Trade Replication Status
+/// Allocation must be a positive share of at most 100%, and the position
+/// cap non-negative
+pub fn is_valid_follow_sizing(allocation_percentage: f64, max_position_size: f64) -> bool {
+    allocation_percentage > 0.0
+        && allocation_percentage <= 100.0
+        && max_position_size.is_finite()
+        && max_position_size >= 0.0
+}
+
+/// A follow relationship with its fee account, as seen by either side
 #[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FollowSummary {
+    pub follower: StrategyFollower,
+    pub fees: Option<FollowerFeeAccount>,
+}
+
+/// Which of the leader's orders a follower copies
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub enum CopyMode {
+    #[default]
+    All,
+    EntriesOnly,
+    ExitsOnly,
+}
+
+/// Follower-side rules applied to every copied order
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct ReplicationPolicy {
+    /// Only copy these tokens; empty allows every token
+    pub allowed_tokens: Vec<String>,
+    pub blocked_tokens: Vec<String>,
+    /// Skip copies when the strategy tolerates more slippage than this
+    pub max_slippage_bps: Option<u16>,
+    pub copy_mode: CopyMode,
+    /// Take the opposite side of every copied order
+    pub reverse: bool,
+    /// Hold copies this long before they may execute
+    pub delay_micros: u64,
+    #[serde(default)]
+    pub version_pin: VersionPin,
+}
+
+/// Which strategy versions a follower copies
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub enum VersionPin {
+    /// Every version
+    #[default]
+    Latest,
+    /// Only while the strategy is at this version
+    Pinned(u64),
+    /// Stop after a risk parameter change until the follower consents again
+    ConsentOnRiskChange,
+}
+
+impl ReplicationPolicy {
+    /// Whether the strategy's current version may be copied, or why not
+    pub fn check_version(&self, strategy: &Strategy, consented_version: u64) -> Result<(), String> {
+        match self.version_pin {
+            VersionPin::Latest => Ok(()),
+            VersionPin::Pinned(version) if strategy.version != version => Err(format!(
+                "strategy at v{}, follower pinned to v{}",
+                strategy.version, version
+            )),
+            VersionPin::Pinned(_) => Ok(()),
+            VersionPin::ConsentOnRiskChange if strategy.risk_version > consented_version => Err(format!(
+                "risk parameters changed in v{}, follower consented to v{}",
+                strategy.risk_version, consented_version
+            )),
+            VersionPin::ConsentOnRiskChange => Ok(()),
+        }
+    }
+
+    /// The order to place for the follower, or why the copy is skipped
+    pub fn copy_order(&self, order: &Order, strategy_slippage_bps: u16) -> Result<Order, String> {
+        let token_is = |tokens: &[String]| tokens.iter().any(|t| t.eq_ignore_ascii_case(&order.token));
+        if !self.allowed_tokens.is_empty() && !token_is(&self.allowed_tokens) {
+            return Err(format!("token {} not in allowlist", order.token));
+        }
+        if token_is(&self.blocked_tokens) {
+            return Err(format!("token {} is blocked", order.token));
+        }
+        if let Some(max) = self.max_slippage_bps.filter(|max| strategy_slippage_bps > *max) {
+            return Err(format!("strategy slippage {} bps exceeds follower max {} bps", strategy_slippage_bps, max));
+        }
+        match (self.copy_mode, order.is_exit()) {
+            (CopyMode::EntriesOnly, true) => return Err("follower copies entries only".to_string()),
+            (CopyMode::ExitsOnly, false) => return Err("follower copies exits only".to_string()),
+            _ => {}
+        }
+        if self.reverse {
+            return order
+                .reversed()
+                .ok_or_else(|| format!("{} orders cannot be reversed", order.order_type.as_str()));
+        }
+        Ok(order.clone())
+    }
+}
+
+/// Trade Replication Status
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub enum ReplicationStatus {
+    /// Follower order created and waiting to execute
     Pending,
+    /// Follower order filled
     Executed,
+    /// Follower order failed, was cancelled or expired
     Failed { reason: String },
+    /// No follower order was created
     Skipped { reason: String },
 }
 
 /// Trade Replication
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct TradeReplication {
+    pub id: u64,
+    pub strategy_id: u64,
     pub original_order_id: u64,
+    /// 0 when the copy was skipped
     pub follower_order_id: u64,
     pub follower_id: String,
     pub scale_factor: f64,
     pub status: ReplicationStatus,
+    pub created_at: u64,
+    /// Earliest time the follower order may execute, from the policy delay
+    pub not_before: u64,
+    /// When the follower order executed, failed or was skipped
+    pub settled_at: Option<u64>,
+}
+
+impl TradeReplication {
+    /// Move a pending replication to its final status. Returns false if it
+    /// was already settled or `status` is `Pending`.
+    pub fn settle(&mut self, status: ReplicationStatus, now: u64) -> bool {
+        if self.status != ReplicationStatus::Pending || status == ReplicationStatus::Pending {
+            return false;
+        }
+        self.status = status;
+        self.settled_at = Some(now);
+        true
+    }
+}
+
+/// Which replications a `GetReplications` query returns
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum ReplicationFilter {
+    /// Copies of one leader order
+    OriginalOrder(u64),
+    /// Copies made for one follower
+    Follower(String),
+    /// Copies of any order from one strategy
+    Strategy(u64),
+}
+
+// ============================================
+// STRATEGY FEES
+// ============================================
+
+/// Highest performance fee an author may charge (50%)
+pub const MAX_PERFORMANCE_FEE_BPS: u16 = 5_000;
+
+/// Fees a strategy author charges followers
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct StrategyFees {
+    /// Share of follower profit above the high-water mark, in basis points
+    pub performance_fee_bps: u16,
+    /// Charged at the start of each subscription period while following
+    pub subscription_fee: f64,
+    pub subscription_period_micros: u64,
+}
+
+impl StrategyFees {
+    pub fn is_valid(&self) -> bool {
+        self.performance_fee_bps <= MAX_PERFORMANCE_FEE_BPS
+            && self.subscription_fee.is_finite()
+            && self.subscription_fee >= 0.0
+            && (self.subscription_fee == 0.0 || self.subscription_period_micros > 0)
+    }
+}
+
+/// Fees one follower owes one strategy's author. Terms are fixed when the
+/// follower subscribes; later fee changes only apply to new follows.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct FollowerFeeAccount {
+    pub strategy_id: u64,
+    pub follower_id: String,
+    pub author: String,
+    pub terms: StrategyFees,
+    /// Cumulative PnL of the follower's replicated trades
+    pub realized_pnl: f64,
+    pub high_water_mark: f64,
+    pub performance_fees_accrued: f64,
+    pub subscription_fees_accrued: f64,
+    pub fees_claimed: f64,
+    /// Start of the first subscription period not yet charged
+    pub subscription_paid_until: u64,
+    pub unfollowed_at: Option<u64>,
+}
+
+impl FollowerFeeAccount {
+    pub fn new(strategy_id: u64, follower_id: String, author: String, terms: StrategyFees, now: u64) -> Self {
+        FollowerFeeAccount {
+            strategy_id,
+            follower_id,
+            author,
+            terms,
+            realized_pnl: 0.0,
+            high_water_mark: 0.0,
+            performance_fees_accrued: 0.0,
+            subscription_fees_accrued: 0.0,
+            fees_claimed: 0.0,
+            subscription_paid_until: now,
+            unfollowed_at: None,
+        }
+    }
+
+    pub fn fees_owed(&self) -> f64 {
+        self.performance_fees_accrued + self.subscription_fees_accrued - self.fees_claimed
+    }
+
+    /// Add replicated-trade PnL and charge the performance fee on any profit
+    /// above the high-water mark. Returns the fee charged.
+    pub fn record_pnl(&mut self, pnl: f64) -> f64 {
+        self.realized_pnl += pnl;
+        if self.realized_pnl <= self.high_water_mark {
+            return 0.0;
+        }
+        let fee = (self.realized_pnl - self.high_water_mark) * self.terms.performance_fee_bps as f64 / 10_000.0;
+        self.high_water_mark = self.realized_pnl;
+        self.performance_fees_accrued += fee;
+        fee
+    }
+
+    /// Charge every subscription period that has started by `now`, or
+    /// before the follower unfollowed. Returns the amount charged.
+    pub fn accrue_subscription(&mut self, now: u64) -> f64 {
+        let period = self.terms.subscription_period_micros;
+        if self.terms.subscription_fee <= 0.0 || period == 0 {
+            return 0.0;
+        }
+        // Exclusive end: periods starting before it are charged
+        let end = match self.unfollowed_at {
+            Some(unfollowed_at) => unfollowed_at.min(now),
+            None => now.saturating_add(1),
+        };
+        if end <= self.subscription_paid_until {
+            return 0.0;
+        }
+        let periods = (end - self.subscription_paid_until - 1) / period + 1;
+        let fee = periods as f64 * self.terms.subscription_fee;
+        self.subscription_paid_until = self.subscription_paid_until.saturating_add(periods.saturating_mul(period));
+        self.subscription_fees_accrued += fee;
+        fee
+    }
+
+    /// Mark everything owed as claimed. Returns the amount claimed.
+    pub fn claim(&mut self) -> f64 {
+        let amount = self.fees_owed();
+        self.fees_claimed += amount;
+        amount
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq)]
+pub enum FeeKind {
+    Performance,
+    Subscription,
+    Claim,
+}
+
+/// Fee ledger entry, visible to both the author and the follower
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FeeLedgerEntry {
+    pub id: u64,
+    pub strategy_id: u64,
+    pub follower_id: String,
+    pub author: String,
+    pub kind: FeeKind,
+    pub amount: f64,
+    pub at: u64,
 }
 
 // ============================================
@@ -294,8 +2246,59 @@ pub struct SafetyConfig {
     pub min_balance_required: f64,
 }
 
+impl SafetyConfig {
+    /// Run the safety checks for an order. Bracket exit orders count as
+    /// protected, since they close the position they belong to.
+    pub fn check_order(&self, order: &Order) -> SafetyCheck {
+        let mut check = SafetyCheck::default();
+
+        // Check position size
+        if order.quantity <= self.max_position_per_token {
+            check.checks_passed.push("position_size".to_string());
+        } else {
+            check.checks_failed.push("position_size_exceeded".to_string());
+            check.status = ValidationStatus::Rejected {
+                reason: format!("Position {} exceeds max {}", order.quantity, self.max_position_per_token),
+            };
+        }
+
+        // Check stop-loss requirement
+        if self.require_stop_loss {
+            if order.bracket.is_some() || order.parent_order_id.is_some() {
+                check.checks_passed.push("stop_loss_check".to_string());
+            } else {
+                check.checks_failed.push("stop_loss_missing".to_string());
+                if check.status == ValidationStatus::Approved {
+                    check.status = ValidationStatus::Rejected {
+                        reason: "Stop-loss required".to_string(),
+                    };
+                }
+            }
+        }
+        check
+    }
+}
+
+/// Result of running a safety config's checks against an order
+#[derive(Clone, Debug)]
+pub struct SafetyCheck {
+    pub status: ValidationStatus,
+    pub checks_passed: Vec<String>,
+    pub checks_failed: Vec<String>,
+}
+
+impl Default for SafetyCheck {
+    fn default() -> Self {
+        SafetyCheck {
+            status: ValidationStatus::Approved,
+            checks_passed: Vec::new(),
+            checks_failed: Vec::new(),
+        }
+    }
+}
+
 /// Order validation status
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub enum ValidationStatus {
     Pending,
     Approved,
@@ -338,22 +2341,40 @@ pub struct StrategyMarketLink {
     pub activate_above: bool,
 }
 
-/// Operations that modify state
+/// Operations that modify state.
+///
+/// Creation operations take an optional `client_request_id`: replaying a
+/// request with the same ID returns the originally assigned ID instead of
+/// creating a duplicate. Fills are idempotent on `(order_id, tx_hash)`.
 #[derive(Debug, Deserialize, Serialize)]
 pub enum Operation {
-    SubmitSignal { signal: Signal },
-    CreateStrategy { strategy: Strategy },
+    SubmitSignal {
+        signal: Signal,
+        client_request_id: Option<String>,
+    },
+    CreateStrategy {
+        strategy: Strategy,
+        client_request_id: Option<String>,
+    },
     ActivateStrategy { strategy_id: u64 },
     DeactivateStrategy { strategy_id: u64 },
-    CreateOrder { order: Order },
+    CreateOrder {
+        order: Order,
+        client_request_id: Option<String>,
+    },
     RecordOrderFill {
         order_id: u64,
         tx_hash: String,
         fill_price: f64,
         filled_at: u64,
     },
+    /// Mark an open order `Failed`, e.g. when the executor's transaction reverted
+    RecordOrderFailure { order_id: u64, reason: String },
     // DEX Operations
-    CreateDEXOrder { order: DEXOrder },
+    CreateDEXOrder {
+        order: DEXOrder,
+        client_request_id: Option<String>,
+    },
     ExecuteDEXOrder { order_id: u64, tx_signature: String },
     // Social Trading Operations
     FollowStrategy {
@@ -361,13 +2382,32 @@ pub enum Operation {
         allocation_percentage: f64,
         max_position_size: f64,
         auto_follow: bool,
+        /// Defaults to copying everything
+        policy: Option<ReplicationPolicy>,
     },
     UnfollowStrategy { strategy_id: u64 },
+    /// Change sizing and policy of an existing follow; `followed_at` is kept
+    UpdateFollow {
+        strategy_id: u64,
+        allocation_percentage: f64,
+        max_position_size: f64,
+        auto_follow: bool,
+        policy: ReplicationPolicy,
+    },
+    /// Stop copying without unfollowing; copies are recorded as skipped
+    PauseFollow { strategy_id: u64 },
+    ResumeFollow { strategy_id: u64 },
+    /// Agree to the strategy's current version, resuming consent-gated copies
+    ConsentStrategyVersion { strategy_id: u64 },
     ReplicateTrade {
         original_order_id: u64,
         follower_id: String,
         scale_factor: f64,
     },
+    /// Bring a follower's subscription fees up to date; anyone may call
+    AccrueFollowerFees { strategy_id: u64, follower_id: String },
+    /// Strategy owner claims everything a follower owes
+    ClaimFollowerFees { strategy_id: u64, follower_id: String },
     // Safety & Validation Operations (Phase 1)
     CreateSafetyConfig { config: SafetyConfig },
     UpdateSafetyConfig { config: SafetyConfig },
@@ -379,25 +2419,200 @@ pub enum Operation {
     LinkStrategyToMarket { link: StrategyMarketLink },
     // Strategy Enhancement Operations (Phase 2)
     UpdateStrategy { strategy: Strategy, change_reason: Option<String> },
+    /// Owner restores an earlier version's rules as a new version
+    RollbackStrategy { strategy_id: u64, to_version: u64 },
+    /// Copy a strategy into the signer's ownership; returns the new strategy id
+    ForkStrategy { strategy_id: u64, overrides: StrategyOverrides },
+    /// Publish the rules behind a committed version; anyone holding the salt may reveal
+    RevealStrategyRules {
+        strategy_id: u64,
+        version: u64,
+        strategy_type: StrategyType,
+        salt: String,
+    },
+    // Curation Operations
+    AddCurator { curator: String },
+    /// Also withdraws the curator's ratings
+    RemoveCurator { curator: String },
+    /// Rate or re-rate a strategy the curator does not own
+    RateStrategy { strategy_id: u64, rating: u8, review: String },
+    UnrateStrategy { strategy_id: u64 },
     GetStrategyHistory { strategy_id: u64 },
     // Execution Engine Operations (Phase 3)
-    CreateMultiHopOrder { order: DEXOrder },
+    CreateMultiHopOrder {
+        order: DEXOrder,
+        client_request_id: Option<String>,
+    },
     CheckConditionalOrders,
+    /// Strategy owner only, while the order is open
     TriggerConditionalOrder { order_id: u64 },
+    /// Strategy owner only, while the order is open
     CancelConditionalOrder { order_id: u64 },
+    CreateSplitRouteOrder {
+        order: DEXOrder,
+        client_request_id: Option<String>,
+    },
+    ExecuteSplitRouteOrder { order_id: u64, leg_fills: Vec<LegFill> },
+    ExecuteOrderSlice {
+        order_id: u64,
+        slice_index: u32,
+        tx_signature: String,
+        output_amount: u64,
+    },
+    PauseDEXOrder { order_id: u64 },
+    ResumeDEXOrder { order_id: u64 },
+    CancelDEXOrderRemainder { order_id: u64 },
+    /// Close a bracketed position manually, cancelling both protective orders
+    CloseBracketPosition { entry_order_id: u64 },
+    /// Expire overdue orders, scanning at most `limit` orders and `limit`
+    /// DEX orders from where the previous sweep stopped
+    SweepExpiredOrders { limit: u32 },
+    // Influencer Reputation Operations
+    RecordSignalPrice {
+        signal_id: u64,
+        horizon: OutcomeHorizon,
+        price: f64,
+    },
+    /// Deprecated: target hits are derived from `ReportTokenPrice`. Resolves
+    /// an open signal at its take-profit or stop-loss.
+    RecordSignalTargetHit { signal_id: u64, hit: TargetHit },
+    /// Price tick for a token contract; advances the lifecycle of a bounded
+    /// number of open signals on that contract, taken in turn so later
+    /// reports reach the rest
+    ReportTokenPrice { contract: String, price: f64 },
+    /// Expire overdue signals, scanning at most `limit` signals from where
+    /// the previous sweep stopped
+    SweepExpiredSignals { limit: u32 },
+    // Signal Anti-Spam Operations (admin only)
+    AuthorizeSignalSubmitter { submitter: String },
+    RevokeSignalSubmitter { submitter: String },
+    SetSignalPolicy { policy: SignalPolicy },
+    // Batch Operations: all-or-nothing, at most `max_batch_size` items.
+    // Every item is checked first; if any is rejected, none is applied.
+    SubmitSignalBatch { signals: Vec<Signal> },
+    CreateOrderBatch { orders: Vec<Order> },
+    RecordOrderFillBatch { fills: Vec<OrderFill> },
+    /// Admin only
+    SetMaxBatchSize { max_batch_size: u32 },
     // Microchain Profile Operations
-    CreateMicrochainProfile { 
+    /// `signature` is by `wallet` over a `WalletLinkProof` for the profile
+    /// `wallet` with the nonce `GetProfileCreationNonce` returns
+    CreateMicrochainProfile {
         name: String,
         wallet: String,
         chains: Vec<String>,
-        visibility: String,
+        visibility: ProfileVisibility,
+        signature: AccountSignature,
+    },
+    /// Unset fields are left unchanged
+    UpdateMicrochainProfile {
+        profile_id: String,
+        name: Option<String>,
+        chains: Option<Vec<String>>,
+        visibility: Option<ProfileVisibility>,
+    },
+    /// `signature` is by `wallet` over a `WalletLinkProof` with the profile's `link_nonce`
+    LinkWallet {
+        profile_id: String,
+        wallet: String,
+        signature: AccountSignature,
     },
+    UnlinkWallet { profile_id: String, wallet: String },
+    DeleteMicrochainProfile { profile_id: String },
+    /// Admin only. While enabled, each new profile gets a chain of its own
+    /// and this chain keeps the directory and leaderboard.
+    SetRegistryMode { enabled: bool },
+    /// Admin only; how long IOC and FOK orders stay live (micros)
+    SetImmediateOrderWindow { window_micros: u64 },
+    /// Admin only. Tokens each new profile chain is opened with, paid from
+    /// this chain's balance, so the chain can pay for its first blocks.
+    /// Further top-ups are up to the trader.
+    SetProfileChainBalance { balance: Amount },
+}
+
+/// Cross-chain messages between the registry chain and profile chains
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum Message {
+    /// Registry to a new profile chain: set up the application there
+    InitProfileChain { profile: MicrochainProfile, admin: Option<String> },
+    /// Profile chain to registry: the profile as it now stands
+    ProfileUpdated { profile: MicrochainProfile },
+    /// Profile chain to registry: a trader's stats after a closed trade
+    ProfileTradeRecorded { owner: String, stats: PerformanceStats, point: EquityPoint },
+    /// Profile chain to registry
+    ProfileDeleted { profile_id: String },
+    /// Registry to a profile chain: a trade closed on the registry by a
+    /// strategy of the trader the chain hosts
+    RecordProfileTrade { owner: String, trade: ClosedTrade },
+}
+
+/// Whether registry mode is on, and the registry a profile chain reports to
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ProfileRegistry {
+    pub enabled: bool,
+    pub registry_chain: Option<ChainId>,
+    /// Opening balance of each new profile chain
+    pub profile_chain_balance: Amount,
+}
+
+/// Default upper bound on items in a batch operation
+pub const DEFAULT_MAX_BATCH_SIZE: u32 = 100;
+
+/// Fill report for one order in a `RecordOrderFillBatch`
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OrderFill {
+    pub order_id: u64,
+    pub tx_hash: String,
+    pub fill_price: f64,
+    pub filled_at: u64,
+}
+
+/// Response to an operation
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub enum OperationResponse {
+    /// ID assigned or affected by a single operation (0 if none)
+    Id(u64),
+    /// Per-item results of a batch operation, in submission order
+    Batch(Vec<BatchItem>),
+}
+
+/// Result of one item of a batch operation
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub enum BatchItem {
+    /// Applied; the ID assigned or affected
+    Applied(u64),
+    Rejected(String),
+    /// Valid, but not applied because another item was rejected
+    NotApplied,
+}
+
+impl BatchItem {
+    /// Per-item results of a batch that is not applied because some item
+    /// was rejected, or `None` if every item passed its checks
+    pub fn rejections(errors: Vec<Option<String>>) -> Option<Vec<BatchItem>> {
+        if errors.iter().all(Option::is_none) {
+            return None;
+        }
+        Some(
+            errors
+                .into_iter()
+                .map(|error| error.map_or(BatchItem::NotApplied, BatchItem::Rejected))
+                .collect(),
+        )
+    }
 }
 
 /// Events emitted by the application
 #[derive(Debug, Deserialize, Serialize)]
 pub enum Event {
     SignalReceived { signal: Signal },
+    SignalRejected {
+        influencer: String,
+        tweet_url: String,
+        reason: SignalError,
+    },
+    SignalSubmitterAuthorized { submitter: String },
+    SignalSubmitterRevoked { submitter: String },
     StrategyCreated { strategy_id: u64, owner: String },
     StrategyActivated { strategy_id: u64 },
     StrategyDeactivated { strategy_id: u64 },
@@ -408,6 +2623,27 @@ pub enum Event {
         fill_price: f64,
     },
     OrderFailed { order_id: u64, reason: String },
+    OrderCancelled { order_id: u64, reason: String },
+    OrderExpired { order_id: u64 },
+    BracketCreated {
+        entry_order_id: u64,
+        stop_loss_order_id: u64,
+        take_profit_order_id: u64,
+    },
+    BracketTriggered {
+        entry_order_id: u64,
+        leg: BracketLeg,
+        filled_order_id: u64,
+        cancelled_order_id: u64,
+    },
+    BracketClosed { entry_order_id: u64 },
+    TradeClosed {
+        strategy_id: u64,
+        entry_order_id: u64,
+        exit_order_id: u64,
+        pnl: f64,
+        return_pct: f64,
+    },
     // DEX Events
     DEXOrderCreated { order: DEXOrder },
     DEXOrderExecuted {
@@ -416,6 +2652,7 @@ pub enum Event {
         output_amount: u64,
     },
     DEXOrderFailed { order_id: u64, reason: String },
+    DEXOrderExpired { order_id: u64 },
     // Social Trading Events
     StrategyFollowed {
         strategy_id: u64,
@@ -425,11 +2662,41 @@ pub enum Event {
         strategy_id: u64,
         follower_id: String,
     },
+    FollowUpdated {
+        strategy_id: u64,
+        follower_id: String,
+    },
+    FollowPaused {
+        strategy_id: u64,
+        follower_id: String,
+        paused: bool,
+    },
     TradeReplicated {
         original_order_id: u64,
         follower_order_id: u64,
         follower_id: String,
     },
+    TradeReplicationSkipped {
+        original_order_id: u64,
+        follower_id: String,
+        reason: String,
+    },
+    ReplicationSettled {
+        replication_id: u64,
+        follower_order_id: u64,
+        status: ReplicationStatus,
+    },
+    FeesAccrued {
+        strategy_id: u64,
+        follower_id: String,
+        kind: FeeKind,
+        amount: f64,
+    },
+    FeesClaimed {
+        strategy_id: u64,
+        follower_id: String,
+        amount: f64,
+    },
     TradeReplicationFailed {
         original_order_id: u64,
         follower_id: String,
@@ -448,12 +2715,70 @@ pub enum Event {
     StrategyTriggeredByMarket { strategy_id: u64, market_id: u64 },
     // Strategy Enhancement Events (Phase 2)
     StrategyUpdated { strategy_id: u64, new_version: u64 },
+    StrategyRolledBack { strategy_id: u64, to_version: u64, new_version: u64 },
+    StrategyForked { strategy_id: u64, owner: String, origin: ForkOrigin },
+    StrategyRulesRevealed { strategy_id: u64, version: u64 },
+    CuratorAdded { curator: String },
+    CuratorRemoved { curator: String },
+    StrategyRated { strategy_id: u64, curator: String, rating: u8 },
+    StrategyUnrated { strategy_id: u64, curator: String },
+    /// Followers with `VersionPin::ConsentOnRiskChange` stop copying until they consent
+    StrategyRiskChanged { strategy_id: u64, version: u64 },
+    FollowConsented { strategy_id: u64, follower_id: String, version: u64 },
     // Execution Engine Events (Phase 3)
     MultiHopOrderCreated { order_id: u64, hop_count: usize },
     ConditionalOrderTriggered { order_id: u64 },
     ConditionalOrderCancelled { order_id: u64 },
+    SplitRouteOrderCreated {
+        order_id: u64,
+        leg_count: usize,
+        expected_output: u64,
+    },
+    SplitRouteOrderExecuted {
+        order_id: u64,
+        leg_fills: Vec<LegFill>,
+        output_amount: u64,
+    },
+    OrderSliceExecuted {
+        order_id: u64,
+        slice_index: u32,
+        tx_signature: String,
+        output_amount: u64,
+    },
+    DEXOrderPaused { order_id: u64 },
+    DEXOrderResumed { order_id: u64 },
+    DEXOrderRemainderCancelled {
+        order_id: u64,
+        cancelled_slices: u32,
+        filled_input: u64,
+    },
+    BatchExecuted { kind: String, items: usize },
+    SignalLifecycleChanged {
+        signal_id: u64,
+        status: SignalLifecycle,
+        realized_return: Option<f64>,
+    },
+    // Influencer Reputation Events
+    SignalOutcomeRecorded {
+        signal_id: u64,
+        influencer: String,
+        horizon: Option<OutcomeHorizon>,
+        target_hit: Option<TargetHit>,
+        reputation_score: f64,
+    },
     // Microchain Events
     MicrochainProfileCreated { wallet: String, name: String },
+    MicrochainProfileUpdated { profile_id: String },
+    WalletLinked { profile_id: String, wallet: String },
+    WalletUnlinked { profile_id: String, wallet: String },
+    MicrochainProfileDeleted { profile_id: String },
+    ProfileChainOpened { profile_id: String, chain_id: ChainId },
+    RegistryModeChanged { enabled: bool },
+    /// An order was rejected at creation, e.g. because its expiry had passed
+    OrderRejected { strategy_id: u64, reason: String },
+    DEXOrderRejected { strategy_id: u64, reason: String },
+    /// No item of the batch was applied
+    BatchRejected { kind: String, rejected: usize },
 }
 
 /// Query operations for read-only access
@@ -461,19 +2786,39 @@ pub enum Event {
 pub enum Query {
     GetSignals { limit: usize, offset: usize },
     GetSignal { id: u64 },
+    GetSignalOutcome { signal_id: u64 },
+    /// Latest signals with their lifecycle and realized return
+    GetSignalFeed { limit: usize, offset: usize },
+    /// Influencers ranked by reputation score, best first
+    GetInfluencers {
+        min_score: Option<f64>,
+        min_signals: Option<u64>,
+        limit: usize,
+    },
+    GetInfluencer { influencer: String },
+    GetSignalSubmitters,
+    GetSignalPolicy,
+    /// Rules are redacted unless the strategy's visibility allows `viewer`.
+    /// `viewer` is not authenticated, so this is advisory only; rules that
+    /// must stay secret should be committed to (`StrategyType::Hidden`) and
+    /// revealed later, not stored.
     GetStrategies {
         owner: Option<String>,
         limit: usize,
         offset: usize,
+        viewer: Option<String>,
     },
-    GetStrategy { id: u64 },
+    GetStrategy { id: u64, viewer: Option<String> },
     GetOrders {
         strategy_id: Option<u64>,
-        status: Option<String>,
+        status: Option<OrderStatus>,
         limit: usize,
         offset: usize,
     },
     GetOrder { id: u64 },
+    GetBracket { entry_order_id: u64 },
+    GetDEXOrder { id: u64 },
+    GetDueOrderSlices { limit: usize },
     // Safety & Validation Queries
     GetSafetyConfig { owner: String },
     GetOrderValidation { order_id: u64 },
@@ -482,9 +2827,59 @@ pub enum Query {
     GetPredictionMarket { id: u64 },
     GetStrategyMarketLinks { strategy_id: u64 },
     // Strategy Enhancement Queries (Phase 2)
-    GetStrategyVersions { strategy_id: u64 },
+    GetStrategyVersions { strategy_id: u64, viewer: Option<String> },
+    GetStrategyDiff {
+        strategy_id: u64,
+        from_version: u64,
+        to_version: u64,
+        viewer: Option<String>,
+    },
+    GetRulesReveal { strategy_id: u64, version: u64 },
+    // Curation Queries
+    GetCurators,
+    GetStrategyReviews { strategy_id: u64 },
+    /// Highest average rating first
+    GetCuratedStrategies {
+        min_rating: f64,
+        limit: usize,
+        offset: usize,
+        viewer: Option<String>,
+    },
+    /// Descendants are listed down to `max_depth` forks below the strategy
+    GetForkTree { strategy_id: u64, max_depth: u32 },
     // Microchain Queries
-    GetMicrochainProfile { wallet: String },
+    /// Any wallet linked to the profile. Wallets and stats are redacted
+    /// unless the profile's visibility allows `viewer`, which is taken on
+    /// trust, so the redaction is advisory.
+    GetMicrochainProfile { wallet: String, viewer: Option<String> },
+    GetProfileRegistry,
+    /// Nonce a `WalletLinkProof` creating a profile for `wallet` must carry
+    GetProfileCreationNonce { wallet: String },
+    // Follow Queries
+    GetFollow { strategy_id: u64, follower_id: String },
+    GetFollowers { strategy_id: u64, limit: usize, offset: usize },
+    /// Follows held by `follower_id`
+    GetFollowing { follower_id: String, limit: usize, offset: usize },
+    // Replication Queries
+    GetReplication { id: u64 },
+    /// Newest first
+    GetReplications { filter: ReplicationFilter, limit: usize, offset: usize },
+    // Fee Queries
+    GetFeeAccount { strategy_id: u64, follower_id: String },
+    /// Accounts where `party` is the follower or the author
+    GetFeeAccounts { party: String },
+    /// Ledger entries where `party` is the follower or the author, newest first
+    GetFeeLedger { party: String, limit: usize, offset: usize },
+    // Risk Metrics Queries
+    GetStrategyRiskMetrics { strategy_id: u64 },
+    /// Profile stats are withheld unless the profile's visibility allows `viewer`
+    GetProfileRiskMetrics { owner: String, viewer: Option<String> },
+    /// Most recent `limit` equity points, oldest first
+    GetStrategyEquity { strategy_id: u64, limit: usize },
+    GetProfileEquity { owner: String, limit: usize, viewer: Option<String> },
+    /// Only profiles visible to everyone are ranked; Private and Followers
+    /// profiles are left out
+    GetLeaderboard { sort_by: LeaderboardSort, min_trades: u64, limit: usize },
     // Network Analytics Query
     GetNetworkAnalytics,
 }
@@ -494,10 +2889,19 @@ pub enum Query {
 pub enum QueryResponse {
     Signals(Vec<Signal>),
     Signal(Option<Signal>),
+    SignalOutcome(Option<SignalOutcome>),
+    SignalFeed(Vec<SignalWithOutcome>),
+    Influencers(Vec<InfluencerStats>),
+    Influencer(Option<InfluencerStats>),
+    SignalSubmitters(Vec<String>),
+    SignalPolicy(SignalPolicy),
     Strategies(Vec<Strategy>),
     Strategy(Option<Strategy>),
     Orders(Vec<Order>),
     Order(Option<Order>),
+    Bracket(Option<Bracket>),
+    DEXOrder(Option<DEXOrder>),
+    DueOrderSlices(Vec<DueSlice>),
     // Safety & Validation Responses
     SafetyConfig(Option<SafetyConfig>),
     OrderValidation(Option<ValidatedOrder>),
@@ -507,12 +2911,310 @@ pub enum QueryResponse {
     StrategyMarketLinks(Vec<StrategyMarketLink>),
     // Strategy Enhancement Responses (Phase 2)
     StrategyVersions(Vec<StrategyVersion>),
+    StrategyDiff(Option<StrategyDiff>),
+    ForkTree(Option<ForkTree>),
+    RulesReveal(Option<RulesReveal>),
+    // Curation Responses
+    Curators(Vec<String>),
+    StrategyReviews(Vec<CuratorReview>),
+    CuratedStrategies(Vec<CuratedStrategy>),
     // Microchain Responses
     MicrochainProfile(Option<MicrochainProfile>),
+    ProfileRegistry(ProfileRegistry),
+    ProfileCreationNonce(u64),
+    // Follow Responses
+    Follow(Option<FollowSummary>),
+    Follows(Vec<FollowSummary>),
+    // Replication Responses
+    Replication(Option<TradeReplication>),
+    Replications(Vec<TradeReplication>),
+    // Fee Responses
+    FeeAccount(Option<FollowerFeeAccount>),
+    FeeAccounts(Vec<FollowerFeeAccount>),
+    FeeLedger(Vec<FeeLedgerEntry>),
+    // Risk Metrics Responses
+    RiskMetrics(Option<RiskMetrics>),
+    EquitySeries(Vec<EquityPoint>),
+    Leaderboard(Vec<LeaderboardEntry>),
     // Network Analytics Response
     NetworkAnalytics(NetworkAnalytics),
 }
 
+// ============================================
+// PERFORMANCE METRICS
+// ============================================
+
+/// Largest peak-to-trough decline of an equity series, as a fraction of
+/// the peak (0.25 = 25%)
+pub fn max_drawdown(equity: &[f64]) -> f64 {
+    let mut peak = f64::MIN;
+    let mut worst = 0.0_f64;
+    for &value in equity {
+        peak = peak.max(value);
+        if peak > 0.0 {
+            worst = worst.max((peak - value) / peak);
+        }
+    }
+    worst
+}
+
+/// Sums of per-trade returns, from which both the batch ratios below and
+/// `PerformanceStats::metrics` are computed
+struct ReturnMoments {
+    count: u64,
+    sum: f64,
+    sq_sum: f64,
+    downside_sq_sum: f64,
+}
+
+impl ReturnMoments {
+    fn of(returns: &[f64]) -> Self {
+        ReturnMoments {
+            count: returns.len() as u64,
+            sum: returns.iter().sum(),
+            sq_sum: returns.iter().map(|r| r * r).sum(),
+            downside_sq_sum: returns.iter().map(|r| r.min(0.0).powi(2)).sum(),
+        }
+    }
+
+    fn mean(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.sum / self.count as f64
+        }
+    }
+
+    /// Population standard deviation
+    fn volatility(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        let mean = self.mean();
+        (self.sq_sum / self.count as f64 - mean * mean).max(0.0).sqrt()
+    }
+
+    /// Root mean square of losses, measured against zero
+    fn downside(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            (self.downside_sq_sum / self.count as f64).sqrt()
+        }
+    }
+
+    fn ratio(&self, deviation: f64) -> f64 {
+        if self.count < 2 || deviation <= 0.0 {
+            0.0
+        } else {
+            self.mean() / deviation
+        }
+    }
+
+    fn sharpe(&self) -> f64 {
+        self.ratio(self.volatility())
+    }
+
+    fn sortino(&self) -> f64 {
+        self.ratio(self.downside())
+    }
+}
+
+/// Mean over population standard deviation of per-trade returns, not
+/// annualised; 0 with fewer than two returns or no dispersion
+pub fn sharpe_ratio(returns: &[f64]) -> f64 {
+    ReturnMoments::of(returns).sharpe()
+}
+
+/// Sortino ratio: mean over downside deviation (losses only, measured
+/// against zero), not annualised; 0 with fewer than two returns or no losses
+pub fn sortino_ratio(returns: &[f64]) -> f64 {
+    ReturnMoments::of(returns).sortino()
+}
+
+/// Equity points kept per strategy or profile; older points are dropped,
+/// while drawdown and return statistics still cover the full history
+pub const MAX_EQUITY_POINTS: usize = 1_000;
+
+/// A realized round trip: a filled entry closed by a filled exit
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ClosedTrade {
+    pub strategy_id: u64,
+    pub entry_order_id: u64,
+    pub exit_order_id: u64,
+    pub quantity: f64,
+    pub entry_price: f64,
+    pub exit_price: f64,
+    pub is_short: bool,
+    pub opened_at: u64,
+    pub closed_at: u64,
+}
+
+impl ClosedTrade {
+    /// Trade from a filled entry order and the filled order that closed it
+    pub fn from_orders(entry: &Order, exit: &Order) -> Option<Self> {
+        let entry_price = entry.fill_price.filter(|p| *p > 0.0)?;
+        let exit_price = exit.fill_price.filter(|p| *p > 0.0)?;
+        Some(ClosedTrade {
+            strategy_id: entry.strategy_id,
+            entry_order_id: entry.id,
+            exit_order_id: exit.id,
+            quantity: entry.quantity,
+            entry_price,
+            exit_price,
+            is_short: entry.is_short(),
+            opened_at: entry.filled_at.unwrap_or(entry.created_at),
+            closed_at: exit.filled_at.unwrap_or(exit.created_at),
+        })
+    }
+
+    fn direction(&self) -> f64 {
+        if self.is_short { -1.0 } else { 1.0 }
+    }
+
+    /// Return on the position (0.1 = +10%)
+    pub fn return_pct(&self) -> f64 {
+        self.direction() * (self.exit_price - self.entry_price) / self.entry_price
+    }
+
+    pub fn pnl(&self) -> f64 {
+        self.direction() * (self.exit_price - self.entry_price) * self.quantity
+    }
+
+    pub fn notional(&self) -> f64 {
+        self.entry_price * self.quantity
+    }
+
+    pub fn hold_micros(&self) -> u64 {
+        self.closed_at.saturating_sub(self.opened_at)
+    }
+}
+
+/// Point on an equity curve
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct EquityPoint {
+    pub timestamp: u64,
+    pub equity: f64,
+}
+
+/// Risk-adjusted performance. Ratios are per trade, not annualised.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct RiskMetrics {
+    pub trades: u64,
+    pub win_rate: f64,
+    /// Compounded return of the equity index (0.1 = +10%)
+    pub roi: f64,
+    pub max_drawdown: f64,
+    /// Standard deviation of per-trade returns
+    pub volatility: f64,
+    pub sharpe_ratio: f64,
+    pub sortino_ratio: f64,
+    pub avg_hold_micros: u64,
+    /// ROI divided by the share of time a position was open
+    pub exposure_adjusted_return: f64,
+}
+
+/// Running trade statistics for a strategy or profile, updated one closed
+/// trade at a time so metrics never need the full trade history
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct PerformanceStats {
+    pub trades: u64,
+    pub winning_trades: u64,
+    pub total_pnl: f64,
+    pub total_volume: f64,
+    /// Equity index: starts at 1.0 and compounds each trade's return
+    pub equity: f64,
+    pub peak_equity: f64,
+    pub max_drawdown: f64,
+    // Sums of per-trade returns for volatility, Sharpe and Sortino
+    pub return_sum: f64,
+    pub return_sq_sum: f64,
+    pub downside_sq_sum: f64,
+    pub total_hold_micros: u64,
+    pub first_opened_at: Option<u64>,
+    pub last_closed_at: Option<u64>,
+}
+
+impl Default for PerformanceStats {
+    fn default() -> Self {
+        PerformanceStats {
+            trades: 0,
+            winning_trades: 0,
+            total_pnl: 0.0,
+            total_volume: 0.0,
+            equity: 1.0,
+            peak_equity: 1.0,
+            max_drawdown: 0.0,
+            return_sum: 0.0,
+            return_sq_sum: 0.0,
+            downside_sq_sum: 0.0,
+            total_hold_micros: 0,
+            first_opened_at: None,
+            last_closed_at: None,
+        }
+    }
+}
+
+impl PerformanceStats {
+    /// Fold in a closed trade and return the new equity point
+    pub fn record_trade(&mut self, trade: &ClosedTrade) -> EquityPoint {
+        let r = trade.return_pct();
+        self.trades += 1;
+        if trade.pnl() > 0.0 {
+            self.winning_trades += 1;
+        }
+        self.total_pnl += trade.pnl();
+        self.total_volume += trade.notional();
+
+        self.equity *= 1.0 + r;
+        self.peak_equity = self.peak_equity.max(self.equity);
+        if self.peak_equity > 0.0 {
+            self.max_drawdown = self.max_drawdown.max((self.peak_equity - self.equity) / self.peak_equity);
+        }
+
+        self.return_sum += r;
+        self.return_sq_sum += r * r;
+        self.downside_sq_sum += r.min(0.0).powi(2);
+        self.total_hold_micros += trade.hold_micros();
+        self.first_opened_at = Some(self.first_opened_at.map_or(trade.opened_at, |t| t.min(trade.opened_at)));
+        self.last_closed_at = Some(self.last_closed_at.map_or(trade.closed_at, |t| t.max(trade.closed_at)));
+
+        EquityPoint { timestamp: trade.closed_at, equity: self.equity }
+    }
+
+    pub fn metrics(&self) -> RiskMetrics {
+        if self.trades == 0 {
+            return RiskMetrics::default();
+        }
+        let n = self.trades as f64;
+        let moments = ReturnMoments {
+            count: self.trades,
+            sum: self.return_sum,
+            sq_sum: self.return_sq_sum,
+            downside_sq_sum: self.downside_sq_sum,
+        };
+
+        let roi = self.equity - 1.0;
+        let span = match (self.first_opened_at, self.last_closed_at) {
+            (Some(first), Some(last)) => last.saturating_sub(first),
+            _ => 0,
+        };
+        let exposure = if span == 0 { 1.0 } else { (self.total_hold_micros as f64 / span as f64).min(1.0) };
+
+        RiskMetrics {
+            trades: self.trades,
+            win_rate: self.winning_trades as f64 / n,
+            roi,
+            max_drawdown: self.max_drawdown,
+            volatility: moments.volatility(),
+            sharpe_ratio: moments.sharpe(),
+            sortino_ratio: moments.sortino(),
+            avg_hold_micros: self.total_hold_micros / self.trades,
+            exposure_adjusted_return: if exposure > 0.0 { roi / exposure } else { roi },
+        }
+    }
+}
+
 use linera_sdk::abi::{ContractAbi, ServiceAbi};
 
 /// Application ABI definition
@@ -520,7 +3222,7 @@ pub struct LineraTradeAbi;
 
 impl ContractAbi for LineraTradeAbi {
     type Operation = Operation;
-    type Response = u64;
+    type Response = OperationResponse;
 }
 
 impl ServiceAbi for LineraTradeAbi {
@@ -13,7 +13,7 @@ mod property_tests {
             influencer: "test".to_string(),
             token: "BTC".to_string(),
             contract: "0x123".to_string(),
-            sentiment: "bullish".to_string(),
+            sentiment: Sentiment::Bullish,
             confidence: 0.95,
             timestamp: 1234567890,
             tweet_url: "https://twitter.com/test".to_string(),
@@ -22,7 +22,7 @@ mod property_tests {
             take_profit: Some(55000.0),
             position_size: Some(1.0),
             leverage: Some(2),
-            platform: Some("DEX".to_string()),
+            platform: Some(Platform::Dex),
         };
 
         assert_eq!(signal.id, 1);
@@ -39,6 +39,21 @@ mod property_tests {
             strategy_type: StrategyType::DSL("buy when price > 50000".to_string()),
             active: true,
             created_at: 1234567890,
+            version: 1,
+            updated_at: None,
+            source: StrategySource::Manual {
+                author: "owner1".to_string(),
+            },
+            risk_percentage: 2.0,
+            max_exposure: 10000.0,
+            slippage_bps: 50,
+            fees: StrategyFees::default(),
+            follower_count: 0,
+            risk_version: 1,
+            forked_from: None,
+            fork_count: 0,
+            visibility: StrategyVisibility::Public,
+            rules_commitment: None,
         };
 
         assert_eq!(strategy.id, 1);
@@ -67,7 +82,7 @@ mod property_tests {
             id: 1,
             strategy_id: 1,
             signal_id: 1,
-            order_type: "market".to_string(),
+            order_type: OrderType::Market,
             token: "BTC".to_string(),
             quantity: 1.0,
             status: OrderStatus::Pending,
@@ -75,12 +90,26 @@ mod property_tests {
             fill_price: None,
             created_at: 1234567890,
             filled_at: None,
+            time_in_force: TimeInForce::GoodTillCancelled,
+            expires_at: None,
+            bracket: None,
+            parent_order_id: None,
+            trigger_price: None,
         };
 
         assert_eq!(order.id, 1);
         assert_eq!(order.strategy_id, 1);
     }
 
+    #[test]
+    fn test_batch_rejections() {
+        assert_eq!(BatchItem::rejections(vec![None, None]), None);
+        assert_eq!(
+            BatchItem::rejections(vec![None, Some("expired".to_string())]),
+            Some(vec![BatchItem::NotApplied, BatchItem::Rejected("expired".to_string())])
+        );
+    }
+
     #[test]
     fn test_operation_enum_compiles() {
         // Verify Operation enum variants can be instantiated
@@ -89,7 +118,7 @@ mod property_tests {
             influencer: "test".to_string(),
             token: "BTC".to_string(),
             contract: "0x123".to_string(),
-            sentiment: "bullish".to_string(),
+            sentiment: Sentiment::Bullish,
             confidence: 0.95,
             timestamp: 1234567890,
             tweet_url: "https://twitter.com/test".to_string(),
@@ -103,6 +132,7 @@ mod property_tests {
 
         let _op = Operation::SubmitSignal {
             signal: signal.clone(),
+            client_request_id: Some("req-1".to_string()),
         };
         let _op2 = Operation::ActivateStrategy { strategy_id: 1 };
         let _op3 = Operation::RecordOrderFill {
@@ -111,6 +141,14 @@ mod property_tests {
             fill_price: 50000.0,
             filled_at: 1234567890,
         };
+        let _op4 = Operation::RecordOrderFillBatch {
+            fills: vec![OrderFill {
+                order_id: 1,
+                tx_hash: "0xabc".to_string(),
+                fill_price: 50000.0,
+                filled_at: 1234567890,
+            }],
+        };
     }
 
     #[test]
@@ -121,7 +159,7 @@ mod property_tests {
             influencer: "test".to_string(),
             token: "BTC".to_string(),
             contract: "0x123".to_string(),
-            sentiment: "bullish".to_string(),
+            sentiment: Sentiment::Bullish,
             confidence: 0.95,
             timestamp: 1234567890,
             tweet_url: "https://twitter.com/test".to_string(),
@@ -159,6 +197,7 @@ mod property_tests {
             owner: Some("owner1".to_string()),
             limit: 10,
             offset: 0,
+            viewer: None,
         };
     }
 
@@ -183,3 +222,1240 @@ mod property_tests {
         _check_service_abi::<LineraTradeAbi>();
     }
 }
+
+#[cfg(test)]
+mod split_route_tests {
+    use super::super::*;
+
+    fn hop(input: &str, output: &str, expected_output: u64) -> RouteHop {
+        RouteHop {
+            dex: DEX::Raydium,
+            input_mint: input.to_string(),
+            output_mint: output.to_string(),
+            pool_address: None,
+            expected_output,
+        }
+    }
+
+    fn leg(weight_bps: u16, hops: Vec<RouteHop>) -> RouteLeg {
+        RouteLeg {
+            weight_bps,
+            hops,
+            tx_signature: None,
+            output_amount: None,
+        }
+    }
+
+    fn split_order(split_legs: Vec<RouteLeg>) -> DEXOrder {
+        DEXOrder {
+            id: 0,
+            strategy_id: 1,
+            dex: DEX::Jupiter,
+            input_mint: "SOL".to_string(),
+            output_mint: "USDC".to_string(),
+            input_amount: 1_000,
+            output_amount: 0,
+            slippage_bps: 50,
+            priority_fee: 0,
+            status: OrderStatus::Pending,
+            tx_signature: None,
+            created_at: 0,
+            executed_at: None,
+            route_path: vec![],
+            is_multi_hop: false,
+            conditional_trigger: None,
+            execution_mode: ExecutionMode::Immediate,
+            split_legs,
+            slices: vec![],
+            paused: false,
+            time_in_force: TimeInForce::GoodTillCancelled,
+            expires_at: None,
+        }
+    }
+
+    fn sixty_forty() -> DEXOrder {
+        split_order(vec![
+            leg(6_000, vec![hop("SOL", "USDC", 600)]),
+            leg(4_000, vec![hop("SOL", "mSOL", 390), hop("mSOL", "USDC", 395)]),
+        ])
+    }
+
+    #[test]
+    fn test_valid_split_route() {
+        let order = sixty_forty();
+        assert_eq!(order.validate_split_route(), Ok(()));
+        assert_eq!(order.expected_split_output(), 995);
+        assert_eq!(order.split_legs[0].input_amount(order.input_amount), 600);
+        assert_eq!(order.split_legs[1].input_amount(order.input_amount), 400);
+    }
+
+    #[test]
+    fn test_split_route_rejects_bad_legs() {
+        let mut order = sixty_forty();
+        order.split_legs[1].weight_bps = 3_000;
+        assert_eq!(order.validate_split_route(), Err(RouteError::WeightsDoNotSum(9_000)));
+
+        let mut order = sixty_forty();
+        order.split_legs[1].hops[0].input_mint = "BONK".to_string();
+        assert_eq!(order.validate_split_route(), Err(RouteError::WrongInputMint(1)));
+
+        let mut order = sixty_forty();
+        order.split_legs[0].hops[0].output_mint = "USDT".to_string();
+        assert_eq!(order.validate_split_route(), Err(RouteError::WrongOutputMint(0)));
+
+        let mut order = sixty_forty();
+        order.split_legs[1].hops[1].input_mint = "jitoSOL".to_string();
+        assert_eq!(order.validate_split_route(), Err(RouteError::DisconnectedHops(1)));
+
+        assert_eq!(split_order(vec![]).validate_split_route(), Err(RouteError::NoLegs));
+    }
+
+    #[test]
+    fn test_apply_leg_fills() {
+        let fill = |leg_index, output_amount| LegFill {
+            leg_index,
+            tx_signature: format!("sig{}", leg_index),
+            output_amount,
+        };
+
+        let mut order = sixty_forty();
+        assert_eq!(order.apply_leg_fills(&[fill(0, 598)]), Err(RouteError::MissingLegFills(1)));
+        assert_eq!(
+            order.apply_leg_fills(&[fill(0, 598), fill(0, 598)]),
+            Err(RouteError::DuplicateLegFill(0))
+        );
+        assert_eq!(order.apply_leg_fills(&[fill(2, 1)]), Err(RouteError::UnknownLeg(2)));
+        assert!(order.split_legs.iter().all(|leg| leg.output_amount.is_none()));
+
+        assert_eq!(order.apply_leg_fills(&[fill(1, 392), fill(0, 598)]), Ok(990));
+        assert_eq!(order.split_legs[1].tx_signature.as_deref(), Some("sig1"));
+        assert_eq!(order.split_legs[0].output_amount, Some(598));
+    }
+
+    #[test]
+    fn test_dex_order_fields_added_later_are_optional_in_json() {
+        let json = r#"{"id": 0, "strategy_id": 1, "dex": "Jupiter", "input_mint": "SOL", "output_mint": "USDC",
+            "input_amount": 1000, "output_amount": 990, "slippage_bps": 50, "priority_fee": 0, "status": "Pending",
+            "tx_signature": null, "created_at": 0, "executed_at": null, "route_path": [], "is_multi_hop": false,
+            "conditional_trigger": null, "execution_mode": "Immediate"}"#;
+        let order: DEXOrder = serde_json::from_str(json).unwrap();
+        assert!(!order.is_split() && order.slices.is_empty() && !order.paused);
+        assert_eq!((order.time_in_force, order.expires_at), (TimeInForce::GoodTillCancelled, None));
+    }
+}
+
+#[cfg(test)]
+mod sliced_execution_tests {
+    use super::super::*;
+
+    fn amounts(slices: &[OrderSlice]) -> Vec<u64> {
+        slices.iter().map(|slice| slice.input_amount).collect()
+    }
+
+    #[test]
+    fn test_twap_slices_are_evenly_spaced() {
+        let mode = ExecutionMode::Twap { slices: 4, interval: 60 };
+        let slices = mode.plan_slices(1_003, 1_000).unwrap();
+        assert_eq!(amounts(&slices), vec![250, 250, 250, 253]);
+        assert_eq!(slices.iter().map(|s| s.due_at).collect::<Vec<_>>(), vec![1_000, 1_060, 1_120, 1_180]);
+        assert!(ExecutionMode::Twap { slices: 0, interval: 60 }.plan_slices(1_000, 0).is_none());
+        // Due times past u64::MAX are rejected rather than wrapped
+        assert!(ExecutionMode::Twap { slices: 3, interval: u64::MAX / 2 }.plan_slices(1_000, 10).is_none());
+    }
+
+    #[test]
+    fn test_vwap_slices_follow_volume_profile() {
+        let mode = ExecutionMode::Vwap { volume_profile: vec![1, 2, 1], interval: 10 };
+        let slices = mode.plan_slices(1_001, 0).unwrap();
+        assert_eq!(amounts(&slices), vec![250, 500, 251]);
+        assert!(ExecutionMode::Vwap { volume_profile: vec![0, 0], interval: 10 }.plan_slices(1_000, 0).is_none());
+        // No zero-amount slices, whether from a zero weight or rounding
+        assert!(ExecutionMode::Vwap { volume_profile: vec![1, 0, 1], interval: 10 }.plan_slices(1_000, 0).is_none());
+        assert!(ExecutionMode::Vwap { volume_profile: vec![1, 1_000], interval: 10 }.plan_slices(100, 0).is_none());
+    }
+
+    #[test]
+    fn test_iceberg_slices_and_due_order() {
+        let mode = ExecutionMode::Iceberg { visible_amount: 400 };
+        let mut slices = mode.plan_slices(1_000, 5).unwrap();
+        assert_eq!(amounts(&slices), vec![400, 400, 200]);
+        assert!(ExecutionMode::Iceberg { visible_amount: 1 }.plan_slices(10_000, 0).is_none());
+
+        slices[0].status = OrderStatus::Filled;
+        slices[0].output_amount = Some(39);
+        let order = DEXOrder {
+            id: 7,
+            strategy_id: 1,
+            dex: DEX::Raydium,
+            input_mint: "SOL".to_string(),
+            output_mint: "USDC".to_string(),
+            input_amount: 1_000,
+            output_amount: 0,
+            slippage_bps: 50,
+            priority_fee: 0,
+            status: OrderStatus::Submitted,
+            tx_signature: None,
+            created_at: 5,
+            executed_at: None,
+            route_path: vec![],
+            is_multi_hop: false,
+            conditional_trigger: None,
+            execution_mode: mode,
+            split_legs: vec![],
+            slices,
+            paused: false,
+            time_in_force: TimeInForce::GoodTillCancelled,
+            expires_at: None,
+        };
+        assert_eq!(order.due_slice(5).map(|s| s.index), Some(1));
+        assert_eq!(order.filled_input(), 400);
+        assert_eq!(order.filled_output(), 39);
+
+        let paused = DEXOrder { paused: true, ..order };
+        assert!(paused.due_slice(5).is_none());
+    }
+}
+
+#[cfg(test)]
+mod bracket_tests {
+    use super::super::*;
+
+    #[test]
+    fn test_bracket_spec_validation() {
+        let long = BracketSpec { stop_loss: 95.0, take_profit: 120.0 };
+        assert!(long.is_valid(false));
+        assert!(!long.is_valid(true));
+
+        let short = BracketSpec { stop_loss: 105.0, take_profit: 80.0 };
+        assert!(short.is_valid(true));
+        assert!(!BracketSpec { stop_loss: 0.0, take_profit: 80.0 }.is_valid(true));
+    }
+
+    #[test]
+    fn test_bracket_spec_from_form_strategy() {
+        let form = FormStrategy {
+            token_pair: "SOL/USDC".to_string(),
+            buy_price: 100.0,
+            sell_target: 120.0,
+            trailing_stop_pct: 2.0,
+            take_profit_pct: 20.0,
+            max_loss_pct: 5.0,
+        };
+        let spec = BracketSpec::from_form(&form, 100.0);
+        assert!((spec.stop_loss - 95.0).abs() < 1e-9);
+        assert!((spec.take_profit - 120.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bracket_leg_lookup() {
+        let bracket = Bracket {
+            entry_order_id: 1,
+            stop_loss_order_id: 2,
+            take_profit_order_id: 3,
+            status: BracketStatus::Active,
+            created_at: 0,
+            closed_at: None,
+        };
+        assert_eq!(bracket.leg_of(2), Some((BracketLeg::StopLoss, 3)));
+        assert_eq!(bracket.leg_of(3), Some((BracketLeg::TakeProfit, 2)));
+        assert_eq!(bracket.leg_of(1), None);
+    }
+
+    #[test]
+    fn test_fill_after_sibling_was_cancelled_is_rejected() {
+        let entry = Order {
+            id: 1,
+            strategy_id: 1,
+            signal_id: 1,
+            order_type: OrderType::Buy,
+            token: "SOL".to_string(),
+            quantity: 1.0,
+            status: OrderStatus::Filled,
+            tx_hash: None,
+            fill_price: Some(100.0),
+            created_at: 0,
+            filled_at: Some(0),
+            time_in_force: TimeInForce::GoodTillCancelled,
+            expires_at: None,
+            bracket: Some(BracketSpec { stop_loss: 95.0, take_profit: 110.0 }),
+            parent_order_id: None,
+            trigger_price: None,
+        };
+        let mut stop = entry.bracket_child(2, BracketLeg::StopLoss, 95.0, 0);
+        let mut take_profit = entry.bracket_child(3, BracketLeg::TakeProfit, 110.0, 0);
+        assert!(stop.can_fill() && take_profit.can_fill());
+
+        // The take-profit fills and its sibling is cancelled
+        take_profit.apply_fill("tx".to_string(), 110.0, 1);
+        stop.status = OrderStatus::Cancelled;
+        assert!(!take_profit.can_fill());
+        assert!(!stop.can_fill());
+
+        for status in [OrderStatus::Failed, OrderStatus::Expired] {
+            assert!(!Order { status, ..stop.clone() }.can_fill());
+        }
+    }
+}
+
+#[cfg(test)]
+mod time_in_force_tests {
+    use super::super::*;
+
+    #[test]
+    fn test_time_in_force_expiry() {
+        let window = DEFAULT_IMMEDIATE_ORDER_WINDOW_MICROS;
+        assert_eq!(TimeInForce::GoodTillCancelled.expires_at(100, window), None);
+        assert_eq!(TimeInForce::GoodTillTime { expires_at: 500 }.expires_at(100, window), Some(500));
+        assert_eq!(TimeInForce::FillOrKill.expires_at(100, window), Some(100 + window));
+        assert_eq!(TimeInForce::ImmediateOrCancel.expires_at(100, 5), Some(105));
+        assert_eq!(TimeInForce::ImmediateOrCancel.expires_at(u64::MAX - 1, 5), Some(u64::MAX));
+        assert_eq!(TimeInForce::default(), TimeInForce::GoodTillCancelled);
+    }
+
+    #[test]
+    fn test_delayed_copy_keeps_the_lifetime() {
+        let gtt = TimeInForce::GoodTillTime { expires_at: 500 };
+        assert_eq!(gtt.delayed(100, 1_000), TimeInForce::GoodTillTime { expires_at: 1_400 });
+        // Already past its deadline when copied
+        assert_eq!(gtt.delayed(600, 1_000), TimeInForce::GoodTillTime { expires_at: 1_000 });
+        assert_eq!(TimeInForce::FillOrKill.delayed(100, 1_000), TimeInForce::FillOrKill);
+        assert_eq!(TimeInForce::FillOrKill.expires_at(1_000, 5), Some(1_005));
+    }
+
+    #[test]
+    fn test_only_open_orders_expire() {
+        assert!(is_expired(Some(500), &OrderStatus::Pending, 500));
+        assert!(is_expired(Some(500), &OrderStatus::Submitted, 900));
+        assert!(!is_expired(Some(500), &OrderStatus::Pending, 499));
+        assert!(!is_expired(Some(500), &OrderStatus::Filled, 900));
+        assert!(!is_expired(None, &OrderStatus::Pending, u64::MAX));
+    }
+}
+
+#[cfg(test)]
+mod client_request_id_tests {
+    use super::super::*;
+
+    #[test]
+    fn test_client_request_id_is_optional_in_json() {
+        let json = r#"{"CreateStrategy":{"strategy":{
+            "id":0,"owner":"alice","name":"s","strategy_type":{"DSL":"buy"},
+            "active":true,"created_at":0,"version":1,"updated_at":null,
+            "source":{"Manual":{"author":"alice"}},
+            "risk_percentage":1.0,"max_exposure":100.0,"slippage_bps":50}}}"#;
+        match serde_json::from_str::<Operation>(json).unwrap() {
+            Operation::CreateStrategy { client_request_id, .. } => assert_eq!(client_request_id, None),
+            other => panic!("unexpected operation {:?}", other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod influencer_reputation_tests {
+    use super::super::*;
+
+    fn signal(sentiment: &str, entry_price: Option<f64>) -> Signal {
+        Signal {
+            id: 1,
+            influencer: "trader".to_string(),
+            token: "SOL".to_string(),
+            contract: "So11111111111111111111111111111111111111112".to_string(),
+            sentiment: Sentiment::parse(sentiment).unwrap(),
+            confidence: 0.8,
+            timestamp: 0,
+            tweet_url: "https://twitter.com/trader/status/1".to_string(),
+            entry_price,
+            stop_loss: None,
+            take_profit: None,
+            position_size: None,
+            leverage: None,
+            platform: None,
+        }
+    }
+
+    #[test]
+    fn test_directional_return() {
+        let long = signal("bullish", Some(100.0));
+        assert!((long.directional_return(110.0).unwrap() - 0.1).abs() < 1e-9);
+        let short = signal("bearish", Some(100.0));
+        assert!((short.directional_return(110.0).unwrap() + 0.1).abs() < 1e-9);
+        assert!(signal("neutral", Some(100.0)).directional_return(110.0).is_none());
+        assert!(signal("bullish", None).directional_return(110.0).is_none());
+    }
+
+    #[test]
+    fn test_reputation_score_rewards_realized_outcomes() {
+        let mut good = InfluencerStats::new("good".to_string());
+        let mut bad = InfluencerStats::new("bad".to_string());
+        assert_eq!(good.reputation_score, 0.5);
+
+        for _ in 0..5 {
+            good.record_return(OutcomeHorizon::OneDay, 0.05);
+            good.record_target_hit(TargetHit::TakeProfit);
+            bad.record_return(OutcomeHorizon::OneDay, -0.05);
+            bad.record_target_hit(TargetHit::StopLoss);
+        }
+
+        assert!(good.reputation_score > 0.8);
+        assert!(bad.reputation_score < 0.2);
+        assert_eq!(good.one_day.evaluations, 5);
+        assert!((good.one_day.average_return() - 0.05).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_reputation_prior_dampens_small_samples() {
+        let mut lucky = InfluencerStats::new("lucky".to_string());
+        lucky.record_return(OutcomeHorizon::OneHour, 0.2);
+        assert!(lucky.reputation_score < 0.7);
+    }
+}
+
+#[cfg(test)]
+mod signal_validation_tests {
+    use super::super::*;
+
+    fn signal() -> Signal {
+        Signal {
+            id: 0,
+            influencer: "trader".to_string(),
+            token: "$SOL".to_string(),
+            contract: "So11111111111111111111111111111111111111112".to_string(),
+            sentiment: Sentiment::Bullish,
+            confidence: 0.9,
+            timestamp: 0,
+            tweet_url: "https://twitter.com/trader/status/1".to_string(),
+            entry_price: Some(100.0),
+            stop_loss: Some(95.0),
+            take_profit: Some(120.0),
+            position_size: None,
+            leverage: Some(3),
+            platform: Some(Platform::Dex),
+        }
+    }
+
+    #[test]
+    fn test_valid_signal() {
+        assert_eq!(signal().validate(), Ok(()));
+        let evm = Signal {
+            contract: "0x6B175474E89094C44Da98b954EedeAC495271d0F".to_string(),
+            ..signal()
+        };
+        assert_eq!(evm.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_signal_structure_is_validated() {
+        let bad = Signal { contract: "0x123".to_string(), ..signal() };
+        assert!(matches!(bad.validate(), Err(SignalError::InvalidContract(_))));
+
+        let bad = Signal { leverage: Some(0), ..signal() };
+        assert_eq!(bad.validate(), Err(SignalError::InvalidLeverage(0)));
+
+        let bad = Signal { confidence: 1.5, ..signal() };
+        assert_eq!(bad.validate(), Err(SignalError::InvalidConfidence(1.5)));
+    }
+
+    #[test]
+    fn test_signal_price_ordering() {
+        let bad = Signal { stop_loss: Some(105.0), ..signal() };
+        assert_eq!(bad.validate(), Err(SignalError::PriceOrdering(Sentiment::Bullish)));
+
+        let short = Signal {
+            sentiment: Sentiment::Bearish,
+            stop_loss: Some(105.0),
+            take_profit: Some(80.0),
+            ..signal()
+        };
+        assert_eq!(short.validate(), Ok(()));
+
+        let partial = Signal { entry_price: None, ..signal() };
+        assert_eq!(partial.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_rate_window() {
+        let policy = SignalPolicy {
+            max_signals_per_window: 2,
+            rate_window_micros: 100,
+            duplicate_window_micros: 0,
+            signal_lifetime_micros: 0,
+        };
+        let mut window = RateWindow::default();
+        assert!(window.try_record(1_000, &policy));
+        assert!(window.try_record(1_050, &policy));
+        assert!(!window.try_record(1_099, &policy));
+        assert!(window.try_record(1_100, &policy));
+
+        // A batch is counted all or none
+        let mut window = RateWindow::default();
+        assert!(window.try_record(1_000, &policy));
+        assert!(!window.try_record_many(1_000, &policy, 2));
+        assert_eq!(window.count, 1);
+        assert!(window.try_record_many(1_100, &policy, 2));
+    }
+}
+
+#[cfg(test)]
+mod signal_lifecycle_tests {
+    use super::super::*;
+
+    const LIFETIME: u64 = 1_000;
+
+    fn signal(sentiment: Sentiment) -> Signal {
+        let (stop_loss, take_profit) = match sentiment {
+            Sentiment::Bearish => (Some(110.0), Some(80.0)),
+            _ => (Some(90.0), Some(120.0)),
+        };
+        Signal {
+            id: 1,
+            influencer: "trader".to_string(),
+            token: "SOL".to_string(),
+            contract: "So11111111111111111111111111111111111111112".to_string(),
+            sentiment,
+            confidence: 0.8,
+            timestamp: 0,
+            tweet_url: "https://twitter.com/trader/status/1".to_string(),
+            entry_price: Some(100.0),
+            stop_loss,
+            take_profit,
+            position_size: None,
+            leverage: None,
+            platform: None,
+        }
+    }
+
+    #[test]
+    fn test_long_runs_entry_then_take_profit() {
+        let long = signal(Sentiment::Bullish);
+        let mut outcome = SignalOutcome::new(1, "trader".to_string(), 0);
+
+        assert!(!outcome.observe_price(&long, 105.0, 10, LIFETIME));
+        assert_eq!(outcome.status, SignalLifecycle::Open);
+
+        assert!(outcome.observe_price(&long, 99.0, 20, LIFETIME));
+        assert_eq!(outcome.status, SignalLifecycle::EntryHit);
+        assert_eq!(outcome.entry_hit_at, Some(20));
+
+        assert!(outcome.observe_price(&long, 125.0, 30, LIFETIME));
+        assert_eq!(outcome.status, SignalLifecycle::TakeProfitHit);
+        assert_eq!(outcome.target_hit, Some(TargetHit::TakeProfit));
+        assert!((outcome.realized_return.unwrap() - 0.2).abs() < 1e-9);
+
+        // Resolved signals ignore further ticks
+        assert!(!outcome.observe_price(&long, 50.0, 40, LIFETIME));
+        assert_eq!(outcome.status, SignalLifecycle::TakeProfitHit);
+    }
+
+    #[test]
+    fn test_short_stops_out() {
+        let short = signal(Sentiment::Bearish);
+        let mut outcome = SignalOutcome::new(1, "trader".to_string(), 0);
+
+        assert!(outcome.observe_price(&short, 101.0, 10, LIFETIME));
+        assert_eq!(outcome.status, SignalLifecycle::EntryHit);
+        assert!(outcome.observe_price(&short, 112.0, 20, LIFETIME));
+        assert_eq!(outcome.status, SignalLifecycle::StopHit);
+        assert!((outcome.realized_return.unwrap() + 0.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_reported_target_hit_resolves_the_lifecycle() {
+        let long = signal(Sentiment::Bullish);
+        let mut outcome = SignalOutcome::new(1, "trader".to_string(), 0);
+        assert!(outcome.apply_target_hit(&long, TargetHit::StopLoss, 10));
+        assert_eq!(outcome.status, SignalLifecycle::StopHit);
+        assert_eq!(outcome.entry_hit_at, Some(10));
+        assert!((outcome.realized_return.unwrap() + 0.1).abs() < 1e-9);
+
+        // Already resolved, or no such target
+        assert!(!outcome.apply_target_hit(&long, TargetHit::TakeProfit, 20));
+        let no_target = Signal { take_profit: None, ..long };
+        let mut outcome = SignalOutcome::new(1, "trader".to_string(), 0);
+        assert!(!outcome.apply_target_hit(&no_target, TargetHit::TakeProfit, 20));
+    }
+
+    #[test]
+    fn test_expiry_marks_to_last_price() {
+        let long = signal(Sentiment::Bullish);
+        let mut never_entered = SignalOutcome::new(1, "trader".to_string(), 0);
+        assert!(never_entered.expire_if_due(&long, LIFETIME, LIFETIME));
+        assert_eq!(never_entered.status, SignalLifecycle::Expired);
+        assert_eq!(never_entered.realized_return, None);
+
+        let mut entered = SignalOutcome::new(2, "trader".to_string(), 0);
+        entered.observe_price(&long, 100.0, 10, LIFETIME);
+        entered.observe_price(&long, 105.0, 20, LIFETIME);
+        assert!(!entered.expire_if_due(&long, LIFETIME - 1, LIFETIME));
+        assert!(entered.expire_if_due(&long, LIFETIME, LIFETIME));
+        assert!((entered.realized_return.unwrap() - 0.05).abs() < 1e-9);
+    }
+}
+
+#[cfg(test)]
+mod typed_enum_tests {
+    use super::super::*;
+
+    #[test]
+    fn test_legacy_strings_parse() {
+        let sentiment: Sentiment = serde_json::from_str("\"BULLISH\"").unwrap();
+        assert_eq!(sentiment, Sentiment::Bullish);
+        let platform: Platform = serde_json::from_str("\"dex\"").unwrap();
+        assert_eq!(platform, Platform::Dex);
+        let visibility: ProfileVisibility = serde_json::from_str("\"gated\"").unwrap();
+        assert_eq!(visibility, ProfileVisibility::Followers);
+        let order_type: OrderType = serde_json::from_str("\"STOP_LOSS\"").unwrap();
+        assert_eq!(order_type, OrderType::StopLossSell);
+    }
+
+    #[test]
+    fn test_invalid_strings_are_rejected() {
+        assert!(serde_json::from_str::<Sentiment>("\"moon\"").is_err());
+        assert!(serde_json::from_str::<Platform>("\"NYSE\"").is_err());
+        assert!(serde_json::from_str::<ProfileVisibility>("\"secret\"").is_err());
+        assert!(serde_json::from_str::<OrderType>("\"yolo\"").is_err());
+    }
+
+    #[test]
+    fn test_wire_format_is_unchanged() {
+        assert_eq!(serde_json::to_string(&Sentiment::Bearish).unwrap(), "\"bearish\"");
+        assert_eq!(serde_json::to_string(&Platform::Cex).unwrap(), "\"CEX\"");
+        assert_eq!(serde_json::to_string(&OrderType::Buy).unwrap(), "\"buy\"");
+        assert_eq!(
+            bcs::to_bytes(&Sentiment::Bullish).unwrap(),
+            bcs::to_bytes(&"bullish".to_string()).unwrap()
+        );
+    }
+}
+
+#[cfg(test)]
+mod shared_logic_tests {
+    use super::super::*;
+
+    #[test]
+    fn test_performance_metrics() {
+        assert_eq!(max_drawdown(&[100.0, 120.0, 90.0, 130.0, 117.0]), 0.25);
+        assert_eq!(max_drawdown(&[]), 0.0);
+        assert_eq!(sharpe_ratio(&[0.1]), 0.0);
+        assert_eq!(sharpe_ratio(&[0.1, 0.1]), 0.0);
+        assert!((sharpe_ratio(&[0.1, -0.1, 0.3]) - (0.1 / (0.08f64 / 3.0).sqrt())).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_bracket_children_trigger_on_the_protective_side() {
+        let entry = Order {
+            id: 1,
+            strategy_id: 1,
+            signal_id: 1,
+            order_type: OrderType::Buy,
+            token: "SOL".to_string(),
+            quantity: 1.0,
+            status: OrderStatus::Filled,
+            tx_hash: None,
+            fill_price: Some(100.0),
+            created_at: 0,
+            filled_at: Some(0),
+            time_in_force: TimeInForce::GoodTillCancelled,
+            expires_at: None,
+            bracket: Some(BracketSpec { stop_loss: 95.0, take_profit: 110.0 }),
+            parent_order_id: None,
+            trigger_price: None,
+        };
+        let stop = entry.bracket_child(2, BracketLeg::StopLoss, 95.0, 0);
+        let take_profit = entry.bracket_child(3, BracketLeg::TakeProfit, 110.0, 0);
+        assert_eq!(stop.order_type, OrderType::StopLossSell);
+        assert!(stop.is_triggered_at(95.0) && !stop.is_triggered_at(96.0));
+        assert!(take_profit.is_triggered_at(111.0) && !take_profit.is_triggered_at(109.0));
+        assert!(!entry.is_triggered_at(1.0));
+
+        let safety = SafetyConfig {
+            id: 0,
+            owner: "owner".to_string(),
+            max_position_per_token: 10.0,
+            max_total_exposure: 0.0,
+            max_slippage_bps: 50,
+            max_loss_percentage: 10.0,
+            require_stop_loss: true,
+            fail_safe_enabled: false,
+            min_balance_required: 0.0,
+        };
+        assert_eq!(safety.check_order(&entry).status, ValidationStatus::Approved);
+        assert_eq!(safety.check_order(&stop).status, ValidationStatus::Approved);
+        let naked = Order { bracket: None, ..entry };
+        assert_eq!(safety.check_order(&naked).checks_failed, vec!["stop_loss_missing".to_string()]);
+    }
+}
+
+#[cfg(test)]
+mod risk_metrics_tests {
+    use super::super::*;
+
+    fn trade(entry_price: f64, exit_price: f64, opened_at: u64, closed_at: u64) -> ClosedTrade {
+        ClosedTrade {
+            strategy_id: 1,
+            entry_order_id: 1,
+            exit_order_id: 2,
+            quantity: 2.0,
+            entry_price,
+            exit_price,
+            is_short: false,
+            opened_at,
+            closed_at,
+        }
+    }
+
+    #[test]
+    fn test_incremental_stats_match_batch_metrics() {
+        let trades = [trade(100.0, 110.0, 0, 10), trade(100.0, 90.0, 20, 30), trade(100.0, 130.0, 50, 100)];
+        let mut stats = PerformanceStats::default();
+        let curve: Vec<f64> = std::iter::once(1.0)
+            .chain(trades.iter().map(|t| stats.record_trade(t).equity))
+            .collect();
+        let returns: Vec<f64> = trades.iter().map(ClosedTrade::return_pct).collect();
+
+        let metrics = stats.metrics();
+        assert_eq!(metrics.trades, 3);
+        assert!((metrics.win_rate - 2.0 / 3.0).abs() < 1e-9);
+        assert!((metrics.roi - (1.1 * 0.9 * 1.3 - 1.0)).abs() < 1e-9);
+        assert!((metrics.max_drawdown - max_drawdown(&curve)).abs() < 1e-9);
+        assert!((metrics.sharpe_ratio - sharpe_ratio(&returns)).abs() < 1e-9);
+        assert!((metrics.sortino_ratio - sortino_ratio(&returns)).abs() < 1e-9);
+        assert_eq!(metrics.avg_hold_micros, 70 / 3);
+        // In the market 70 of 100 micros
+        assert!((metrics.exposure_adjusted_return - metrics.roi / 0.7).abs() < 1e-9);
+        assert_eq!(stats.total_pnl, 20.0 - 20.0 + 60.0);
+    }
+
+    #[test]
+    fn test_short_trades_profit_when_price_falls() {
+        let short = ClosedTrade { is_short: true, ..trade(100.0, 80.0, 0, 1) };
+        assert!((short.return_pct() - 0.2).abs() < 1e-9);
+        assert_eq!(short.pnl(), 40.0);
+    }
+
+    #[test]
+    fn test_leaderboard_sort_ranks_shallow_drawdowns_first() {
+        let shallow = RiskMetrics { max_drawdown: 0.1, ..RiskMetrics::default() };
+        let deep = RiskMetrics { max_drawdown: 0.4, ..RiskMetrics::default() };
+        let sort = LeaderboardSort::MaxDrawdown;
+        assert!(sort.key(&shallow) > sort.key(&deep));
+    }
+}
+
+#[cfg(test)]
+mod strategy_fee_tests {
+    use super::super::*;
+
+    const DAY: u64 = 86_400_000_000;
+
+    fn account(performance_fee_bps: u16, subscription_fee: f64) -> FollowerFeeAccount {
+        let terms = StrategyFees { performance_fee_bps, subscription_fee, subscription_period_micros: DAY };
+        FollowerFeeAccount::new(1, "follower".to_string(), "author".to_string(), terms, 0)
+    }
+
+    #[test]
+    fn test_performance_fee_uses_high_water_mark() {
+        let mut account = account(2_000, 0.0);
+        assert_eq!(account.record_pnl(100.0), 20.0);
+        // Losses earn nothing, and recovering them earns nothing either
+        assert_eq!(account.record_pnl(-50.0), 0.0);
+        assert_eq!(account.record_pnl(50.0), 0.0);
+        assert_eq!(account.record_pnl(25.0), 5.0);
+        assert_eq!(account.high_water_mark, 125.0);
+        assert_eq!(account.fees_owed(), 25.0);
+    }
+
+    #[test]
+    fn test_subscription_charges_each_started_period() {
+        let mut account = account(0, 10.0);
+        assert_eq!(account.accrue_subscription(0), 10.0);
+        assert_eq!(account.accrue_subscription(DAY - 1), 0.0);
+        assert_eq!(account.accrue_subscription(3 * DAY), 30.0);
+
+        // No periods start after unfollowing
+        account.unfollowed_at = Some(4 * DAY);
+        assert_eq!(account.accrue_subscription(10 * DAY), 0.0);
+        assert_eq!(account.claim(), 40.0);
+        assert_eq!(account.fees_owed(), 0.0);
+    }
+
+    #[test]
+    fn test_fee_terms_validation() {
+        assert!(StrategyFees::default().is_valid());
+        assert!(!StrategyFees { performance_fee_bps: MAX_PERFORMANCE_FEE_BPS + 1, ..StrategyFees::default() }.is_valid());
+        assert!(!StrategyFees { subscription_fee: 5.0, ..StrategyFees::default() }.is_valid());
+        assert!(!StrategyFees { subscription_fee: -1.0, subscription_period_micros: DAY, ..StrategyFees::default() }.is_valid());
+    }
+}
+
+#[cfg(test)]
+mod replication_policy_tests {
+    use super::super::*;
+
+    fn order(order_type: OrderType, token: &str) -> Order {
+        Order {
+            id: 1,
+            strategy_id: 1,
+            signal_id: 1,
+            order_type,
+            token: token.to_string(),
+            quantity: 1.0,
+            status: OrderStatus::Pending,
+            tx_hash: None,
+            fill_price: None,
+            created_at: 0,
+            filled_at: None,
+            time_in_force: TimeInForce::GoodTillCancelled,
+            expires_at: None,
+            bracket: Some(BracketSpec { stop_loss: 90.0, take_profit: 120.0 }),
+            parent_order_id: None,
+            trigger_price: None,
+        }
+    }
+
+    #[test]
+    fn test_token_and_slippage_rules_skip_copies() {
+        let policy = ReplicationPolicy {
+            allowed_tokens: vec!["sol".to_string(), "BONK".to_string()],
+            blocked_tokens: vec!["BONK".to_string()],
+            max_slippage_bps: Some(30),
+            ..ReplicationPolicy::default()
+        };
+        assert!(policy.copy_order(&order(OrderType::Buy, "SOL"), 30).is_ok());
+        assert_eq!(
+            policy.copy_order(&order(OrderType::Buy, "ETH"), 30).unwrap_err(),
+            "token ETH not in allowlist"
+        );
+        assert_eq!(policy.copy_order(&order(OrderType::Buy, "BONK"), 30).unwrap_err(), "token BONK is blocked");
+        assert!(policy.copy_order(&order(OrderType::Buy, "SOL"), 50).is_err());
+    }
+
+    #[test]
+    fn test_copy_mode_separates_entries_and_exits() {
+        let entries_only = ReplicationPolicy { copy_mode: CopyMode::EntriesOnly, ..ReplicationPolicy::default() };
+        let exits_only = ReplicationPolicy { copy_mode: CopyMode::ExitsOnly, ..ReplicationPolicy::default() };
+        let entry = order(OrderType::Buy, "SOL");
+        let exit = order(OrderType::StopLossSell, "SOL");
+        assert!(entries_only.copy_order(&entry, 0).is_ok());
+        assert!(entries_only.copy_order(&exit, 0).is_err());
+        assert!(exits_only.copy_order(&entry, 0).is_err());
+        assert!(exits_only.copy_order(&exit, 0).is_ok());
+    }
+
+    #[test]
+    fn test_reverse_mirrors_side_and_bracket() {
+        let reverse = ReplicationPolicy { reverse: true, ..ReplicationPolicy::default() };
+        let short = reverse.copy_order(&order(OrderType::Buy, "SOL"), 0).unwrap();
+        assert_eq!(short.order_type, OrderType::Sell);
+        let bracket = short.bracket.unwrap();
+        assert!(bracket.is_valid(true));
+        assert_eq!((bracket.stop_loss, bracket.take_profit), (120.0, 90.0));
+
+        let stop = reverse.copy_order(&order(OrderType::StopLossSell, "SOL"), 0).unwrap();
+        assert_eq!(stop.order_type, OrderType::TakeProfitBuy);
+        assert!(reverse.copy_order(&order(OrderType::Market, "SOL"), 0).is_err());
+    }
+}
+
+#[cfg(test)]
+mod follow_lifecycle_tests {
+    use super::super::*;
+
+    #[test]
+    fn test_follow_sizing_validation() {
+        assert!(is_valid_follow_sizing(100.0, 0.0));
+        assert!(is_valid_follow_sizing(0.5, 1_000.0));
+        assert!(!is_valid_follow_sizing(0.0, 1_000.0));
+        assert!(!is_valid_follow_sizing(100.1, 1_000.0));
+        assert!(!is_valid_follow_sizing(f64::NAN, 1_000.0));
+        assert!(!is_valid_follow_sizing(50.0, -1.0));
+    }
+
+    #[test]
+    fn test_follow_strategy_policy_is_optional_in_json() {
+        let json = r#"{"FollowStrategy": {"strategy_id": 1, "allocation_percentage": 10.0, "max_position_size": 500.0, "auto_follow": true}}"#;
+        match serde_json::from_str::<Operation>(json).unwrap() {
+            Operation::FollowStrategy { policy, .. } => assert!(policy.is_none()),
+            other => panic!("unexpected operation {:?}", other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod replication_status_tests {
+    use super::super::*;
+
+    fn replication() -> TradeReplication {
+        TradeReplication {
+            id: 1,
+            strategy_id: 2,
+            original_order_id: 3,
+            follower_order_id: 4,
+            follower_id: "follower".to_string(),
+            scale_factor: 0.5,
+            status: ReplicationStatus::Pending,
+            created_at: 10,
+            not_before: 10,
+            settled_at: None,
+        }
+    }
+
+    #[test]
+    fn test_pending_replication_settles_once() {
+        let mut replication = replication();
+        assert!(replication.settle(ReplicationStatus::Executed, 20));
+        assert_eq!(replication.settled_at, Some(20));
+
+        let failed = ReplicationStatus::Failed { reason: "cancelled".to_string() };
+        assert!(!replication.settle(failed, 30));
+        assert_eq!(replication.status, ReplicationStatus::Executed);
+        assert_eq!(replication.settled_at, Some(20));
+    }
+
+    #[test]
+    fn test_settle_requires_a_final_status() {
+        let mut replication = replication();
+        assert!(!replication.settle(ReplicationStatus::Pending, 20));
+        assert_eq!(replication.settled_at, None);
+    }
+}
+
+#[cfg(test)]
+mod strategy_version_tests {
+    use super::super::*;
+
+    fn strategy(version: u64, risk_version: u64) -> Strategy {
+        Strategy {
+            id: 1,
+            owner: "owner".to_string(),
+            name: "SOL swing".to_string(),
+            strategy_type: StrategyType::Form(FormStrategy {
+                token_pair: "SOL/USDC".to_string(),
+                buy_price: 100.0,
+                sell_target: 120.0,
+                trailing_stop_pct: 0.0,
+                take_profit_pct: 20.0,
+                max_loss_pct: 5.0,
+            }),
+            active: true,
+            created_at: 0,
+            version,
+            updated_at: None,
+            source: StrategySource::Manual { author: "owner".to_string() },
+            risk_percentage: 2.0,
+            max_exposure: 1_000.0,
+            slippage_bps: 50,
+            fees: StrategyFees::default(),
+            follower_count: 0,
+            risk_version,
+            forked_from: None,
+            fork_count: 0,
+            visibility: StrategyVisibility::Public,
+            rules_commitment: None,
+        }
+    }
+
+    #[test]
+    fn test_diff_lists_changed_fields_with_risk_flags() {
+        let from = strategy(1, 1);
+        let mut to = strategy(2, 1);
+        to.name = "SOL swing v2".to_string();
+        to.slippage_bps = 100;
+        to.follower_count = 9;
+
+        let changes = from.diff(&to);
+        assert_eq!(
+            changes,
+            vec![
+                FieldChange {
+                    field: "name".to_string(),
+                    from: "\"SOL swing\"".to_string(),
+                    to: "\"SOL swing v2\"".to_string(),
+                    risk: false,
+                },
+                FieldChange {
+                    field: "slippage_bps".to_string(),
+                    from: "50".to_string(),
+                    to: "100".to_string(),
+                    risk: true,
+                },
+            ]
+        );
+        assert!(from.risk_changed(&to));
+        assert!(from.diff(&strategy(3, 1)).is_empty());
+    }
+
+    #[test]
+    fn test_switching_strategy_type_is_a_risk_change() {
+        let from = strategy(1, 1);
+        let to = Strategy { strategy_type: StrategyType::DSL("buy when bullish".to_string()), ..strategy(2, 1) };
+        let changes = from.diff(&to);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].field, "strategy_type");
+        assert!(changes[0].risk);
+
+        let renamed = Strategy { name: "renamed".to_string(), ..strategy(2, 1) };
+        assert!(!from.risk_changed(&renamed));
+    }
+
+    #[test]
+    fn test_next_version_keeps_immutable_fields() {
+        let current = Strategy { follower_count: 4, created_at: 10, ..strategy(2, 1) };
+        let proposed = Strategy {
+            id: 99,
+            owner: "attacker".to_string(),
+            created_at: 0,
+            source: StrategySource::Curated { curator: "self".to_string(), rating: 5.0 },
+            version: 42,
+            follower_count: 0,
+            risk_version: 0,
+            name: "renamed".to_string(),
+            max_exposure: 5_000.0,
+            ..strategy(2, 1)
+        };
+
+        let next = current.next_version(proposed, 50);
+        assert_eq!((next.id, next.owner.as_str(), next.created_at), (1, "owner", 10));
+        assert_eq!(next.source, current.source);
+        assert_eq!((next.version, next.updated_at, next.follower_count), (3, Some(50), 4));
+        assert_eq!(next.name, "renamed");
+        // max_exposure is a risk parameter
+        assert_eq!(next.risk_version, 3);
+
+        let renamed = current.next_version(Strategy { name: "again".to_string(), ..current.clone() }, 60);
+        assert_eq!(renamed.risk_version, 1);
+    }
+
+    #[test]
+    fn test_fork_records_provenance_and_applies_overrides() {
+        let parent = Strategy {
+            follower_count: 3,
+            fork_count: 2,
+            fees: StrategyFees { performance_fee_bps: 1_000, ..StrategyFees::default() },
+            ..strategy(4, 2)
+        };
+        let overrides = StrategyOverrides { slippage_bps: Some(25), ..StrategyOverrides::default() };
+
+        let fork = parent.fork("forker".to_string(), overrides);
+        assert_eq!(fork.owner, "forker");
+        assert_eq!(fork.source, StrategySource::Manual { author: "forker".to_string() });
+        assert_eq!(fork.forked_from, Some(ForkOrigin { strategy_id: 1, version: 4 }));
+        assert_eq!(fork.name, "SOL swing (fork)");
+        assert_eq!(fork.strategy_type, parent.strategy_type);
+        assert_eq!((fork.slippage_bps, fork.max_exposure), (25, 1_000.0));
+        assert_eq!(fork.fees, StrategyFees::default());
+        assert!(!fork.active);
+        assert_eq!((fork.follower_count, fork.fork_count), (0, 0));
+    }
+
+    #[test]
+    fn test_next_version_keeps_provenance() {
+        let origin = ForkOrigin { strategy_id: 9, version: 1 };
+        let current = Strategy { forked_from: Some(origin.clone()), fork_count: 5, ..strategy(1, 1) };
+        let next = current.next_version(strategy(1, 1), 10);
+        assert_eq!(next.forked_from, Some(origin));
+        assert_eq!(next.fork_count, 5);
+    }
+
+    #[test]
+    fn test_hidden_rules_need_a_commitment() {
+        let commitment = rules_commitment(&StrategyType::DSL("buy".to_string()), "salt");
+        let committed = Strategy { strategy_type: StrategyType::Hidden, rules_commitment: Some(commitment.clone()), ..strategy(1, 1) };
+        assert!(committed.rules_are_valid());
+        assert!(!Strategy { rules_commitment: None, ..committed.clone() }.rules_are_valid());
+        assert!(!Strategy { rules_commitment: Some(commitment), ..strategy(1, 1) }.rules_are_valid());
+
+        let redacted = strategy(1, 1).redacted();
+        assert_eq!(redacted.strategy_type, StrategyType::Hidden);
+        assert_eq!(redacted.name, "SOL swing");
+    }
+
+    #[test]
+    fn test_version_pins() {
+        let current = strategy(3, 3);
+        let policy = |version_pin| ReplicationPolicy { version_pin, ..ReplicationPolicy::default() };
+
+        assert!(policy(VersionPin::Latest).check_version(&current, 1).is_ok());
+        assert!(policy(VersionPin::Pinned(3)).check_version(&current, 1).is_ok());
+        assert!(policy(VersionPin::Pinned(2)).check_version(&current, 1).is_err());
+        assert!(policy(VersionPin::ConsentOnRiskChange).check_version(&current, 2).is_err());
+        assert!(policy(VersionPin::ConsentOnRiskChange).check_version(&current, 3).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod strategy_visibility_tests {
+    use super::super::*;
+
+    fn dsl(source: &str) -> StrategyType {
+        StrategyType::DSL(source.to_string())
+    }
+
+    #[test]
+    fn test_visibility_levels() {
+        assert!(StrategyVisibility::Public.allows(false, false));
+        assert!(StrategyVisibility::FollowersOnly.allows(false, true));
+        assert!(!StrategyVisibility::FollowersOnly.allows(false, false));
+        assert!(StrategyVisibility::Private.allows(true, false));
+        assert!(!StrategyVisibility::Private.allows(false, true));
+    }
+
+    #[test]
+    fn test_rules_commitment_binds_rules_and_salt() {
+        let commitment = rules_commitment(&dsl("buy when bullish"), "salt");
+        assert_eq!(commitment, rules_commitment(&dsl("buy when bullish"), "salt"));
+        assert_ne!(commitment, rules_commitment(&dsl("buy when bearish"), "salt"));
+        assert_ne!(commitment, rules_commitment(&dsl("buy when bullish"), "pepper"));
+    }
+
+    #[test]
+    fn test_diff_redaction_hides_rule_values_only() {
+        let diff = StrategyDiff {
+            strategy_id: 1,
+            from_version: 1,
+            to_version: 2,
+            changes: vec![
+                FieldChange { field: "form.max_loss_pct".to_string(), from: "5.0".to_string(), to: "8.0".to_string(), risk: true },
+                FieldChange { field: "slippage_bps".to_string(), from: "50".to_string(), to: "80".to_string(), risk: true },
+            ],
+        };
+        let redacted = diff.redacted();
+        assert_eq!((redacted.changes[0].from.as_str(), redacted.changes[0].to.as_str()), ("hidden", "hidden"));
+        assert_eq!(redacted.changes[0].field, "form.max_loss_pct");
+        assert_eq!(redacted.changes[1].to, "80");
+    }
+}
+
+#[cfg(test)]
+mod curation_tests {
+    use super::super::*;
+
+    #[test]
+    fn test_review_validation() {
+        assert!(is_valid_review(MIN_CURATOR_RATING, ""));
+        assert!(is_valid_review(MAX_CURATOR_RATING, "solid risk controls"));
+        assert!(!is_valid_review(0, ""));
+        assert!(!is_valid_review(MAX_CURATOR_RATING + 1, ""));
+        assert!(!is_valid_review(3, &"x".repeat(MAX_REVIEW_LEN + 1)));
+    }
+
+    #[test]
+    fn test_rating_summary_tracks_current_ratings() {
+        let mut ratings = RatingSummary::default();
+        assert_eq!(ratings.average(), 0.0);
+
+        ratings.add(5);
+        ratings.add(2);
+        assert_eq!(ratings.average(), 3.5);
+
+        // A re-rating withdraws the old rating first
+        ratings.remove(2);
+        ratings.add(4);
+        assert_eq!((ratings.rating_count, ratings.average()), (2, 4.5));
+
+        ratings.remove(5);
+        ratings.remove(4);
+        assert_eq!(ratings, RatingSummary::default());
+    }
+}
+
+#[cfg(test)]
+mod profile_management_tests {
+    use super::super::*;
+    use linera_sdk::linera_base_types::{AccountOwner, AccountSecretKey, Ed25519SecretKey};
+
+    fn secret_key(byte: u8) -> AccountSecretKey {
+        let hex = format!("\"{}\"", format!("{:02x}", byte).repeat(32));
+        AccountSecretKey::Ed25519(serde_json::from_str::<Ed25519SecretKey>(&hex).unwrap())
+    }
+
+    fn proof(wallet: String, nonce: u64) -> WalletLinkProof {
+        WalletLinkProof {
+            chain_id: "aa".repeat(32).parse().unwrap(),
+            application_id: "cc".repeat(32).parse().unwrap(),
+            profile_id: "primary".to_string(),
+            wallet,
+            nonce,
+        }
+    }
+
+    #[test]
+    fn test_link_proof_must_be_signed_by_the_wallet() {
+        let key = secret_key(1);
+        let wallet = AccountOwner::from(key.public()).to_string();
+        let signature = key.sign(&proof(wallet.clone(), 0));
+
+        assert!(proof(wallet.clone(), 0).is_signed_by_wallet(&signature));
+        // Replayed against the next nonce
+        assert!(!proof(wallet.clone(), 1).is_signed_by_wallet(&signature));
+        // Replayed on the profile's other chain
+        let elsewhere = WalletLinkProof { chain_id: "bb".repeat(32).parse().unwrap(), ..proof(wallet.clone(), 0) };
+        assert!(!elsewhere.is_signed_by_wallet(&signature));
+        // Signed by someone else
+        let other = secret_key(2).sign(&proof(wallet.clone(), 0));
+        assert!(!proof(wallet, 0).is_signed_by_wallet(&other));
+    }
+
+    #[test]
+    fn test_profile_is_managed_by_owner_and_linked_wallets() {
+        let profile = MicrochainProfile {
+            id: "primary".to_string(),
+            name: "trader".to_string(),
+            wallets: vec!["primary".to_string(), "second".to_string()],
+            preferred_chains: Vec::new(),
+            visibility: ProfileVisibility::Public,
+            created_at: 0,
+            owner: "signer".to_string(),
+            updated_at: None,
+            link_nonce: 1,
+            chain_id: None,
+            total_trades: 0,
+            winning_trades: 0,
+            total_volume: 0,
+            total_pnl: 0,
+        };
+        assert!(profile.is_managed_by("signer"));
+        assert!(profile.is_managed_by("second"));
+        assert!(!profile.is_managed_by("stranger"));
+
+        let redacted = MicrochainProfile { total_trades: 7, total_pnl: -3, ..profile }.redacted();
+        assert_eq!((redacted.id.as_str(), redacted.name.as_str()), ("primary", "trader"));
+        assert!(redacted.wallets.is_empty() && redacted.owner.is_empty());
+        assert_eq!((redacted.total_trades, redacted.total_pnl), (0, 0));
+    }
+
+    #[test]
+    fn test_profile_is_hosted_on_its_own_chain() {
+        let registry: ChainId = "aa".repeat(32).parse().unwrap();
+        let own: ChainId = "bb".repeat(32).parse().unwrap();
+        let profile = MicrochainProfile {
+            id: "primary".to_string(),
+            name: "trader".to_string(),
+            wallets: vec!["primary".to_string()],
+            preferred_chains: Vec::new(),
+            visibility: ProfileVisibility::Public,
+            created_at: 0,
+            owner: "signer".to_string(),
+            updated_at: None,
+            link_nonce: 0,
+            chain_id: None,
+            total_trades: 0,
+            winning_trades: 0,
+            total_volume: 0,
+            total_pnl: 0,
+        };
+        assert!(profile.is_hosted_on(registry));
+
+        let profile = MicrochainProfile { chain_id: Some(own), ..profile };
+        assert!(profile.is_hosted_on(own));
+        assert!(!profile.is_hosted_on(registry));
+    }
+
+    #[test]
+    fn test_profile_visibility_levels() {
+        assert!(ProfileVisibility::Public.allows(false, false));
+        assert!(ProfileVisibility::Followers.allows(false, true));
+        assert!(!ProfileVisibility::Followers.allows(false, false));
+        assert!(ProfileVisibility::Private.allows(true, false));
+        assert!(!ProfileVisibility::Private.allows(false, true));
+    }
+}
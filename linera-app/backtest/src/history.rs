@@ -0,0 +1,99 @@
+// Price and signal histories for backtests, loaded from JSON or CSV
+
+use abi::Signal;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Observed price of a token at a point in time (microseconds, like the chain)
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct PriceTick {
+    pub timestamp: u64,
+    pub token: String,
+    pub price: f64,
+}
+
+/// Prices and signals to replay. Neither list needs to be sorted.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct History {
+    #[serde(default)]
+    pub prices: Vec<PriceTick>,
+    #[serde(default)]
+    pub signals: Vec<Signal>,
+}
+
+/// One step of a replay
+#[derive(Clone, Debug)]
+pub enum HistoryEvent<'a> {
+    Price(&'a PriceTick),
+    Signal(&'a Signal),
+}
+
+impl HistoryEvent<'_> {
+    pub fn timestamp(&self) -> u64 {
+        match self {
+            HistoryEvent::Price(tick) => tick.timestamp,
+            HistoryEvent::Signal(signal) => signal.timestamp,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum HistoryError {
+    #[error("invalid JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("CSV line {line}: {reason}")]
+    Csv { line: usize, reason: String },
+}
+
+impl History {
+    /// `{"prices": [...], "signals": [...]}`
+    pub fn from_json(input: &str) -> Result<Self, HistoryError> {
+        Ok(serde_json::from_str(input)?)
+    }
+
+    /// A JSON array of signals
+    pub fn signals_from_json(input: &str) -> Result<Vec<Signal>, HistoryError> {
+        Ok(serde_json::from_str(input)?)
+    }
+
+    /// A JSON array of price ticks
+    pub fn prices_from_json(input: &str) -> Result<Vec<PriceTick>, HistoryError> {
+        Ok(serde_json::from_str(input)?)
+    }
+
+    /// `timestamp,token,price` rows; a header row and blank lines are skipped
+    pub fn prices_from_csv(input: &str) -> Result<Vec<PriceTick>, HistoryError> {
+        let mut prices = Vec::new();
+        for (index, line) in input.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || (index == 0 && line.starts_with("timestamp")) {
+                continue;
+            }
+            let csv_error = |reason: &str| HistoryError::Csv { line: index + 1, reason: reason.to_string() };
+
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let [timestamp, token, price] = fields[..] else {
+                return Err(csv_error("expected timestamp,token,price"));
+            };
+            prices.push(PriceTick {
+                timestamp: timestamp.parse().map_err(|_| csv_error("invalid timestamp"))?,
+                token: token.to_string(),
+                price: price.parse().map_err(|_| csv_error("invalid price"))?,
+            });
+        }
+        Ok(prices)
+    }
+
+    /// Prices and signals in time order. A price and a signal at the same
+    /// timestamp replay price first, so the signal sees the latest price.
+    pub fn events(&self) -> Vec<HistoryEvent<'_>> {
+        let mut events: Vec<HistoryEvent<'_>> = self
+            .prices
+            .iter()
+            .map(HistoryEvent::Price)
+            .chain(self.signals.iter().map(HistoryEvent::Signal))
+            .collect();
+        events.sort_by_key(HistoryEvent::timestamp);
+        events
+    }
+}
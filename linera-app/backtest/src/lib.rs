@@ -0,0 +1,415 @@
+// Native backtesting for trade-ai strategies.
+//
+// Replays a price and signal history through the order, bracket and safety
+// types the contract uses from `abi`. It is a simulation, not a replay of
+// the chain, and approximates it in these ways:
+//
+// - Entries: the contract never opens positions on its own. Orders come
+//   from `CreateOrder` calls, while here a form strategy takes any signal
+//   `FormStrategy::accepts_signal` passes, and the confidence cut-off
+//   `FORM_MIN_SIGNAL_CONFIDENCE` exists only in this simulation.
+// - Exits: on chain a bracket leg fills only when a `RecordOrderFill`
+//   arrives. Here a leg fills at the first tick that reaches its trigger
+//   (`Order::is_triggered_at`), at the tick price.
+// - Form fields: `sell_target` and `trailing_stop_pct` are ignored; the
+//   bracket comes from the signal's targets or from `max_loss_pct` and
+//   `take_profit_pct` (`BracketSpec::from_form`).
+// - DSL strategies are not supported.
+//
+// Sharpe is the per-trade, non-annualised ratio `PerformanceStats::metrics`
+// reports on chain, so both agree for the same trades. Drawdown is measured
+// on the cash equity curve marked at every tick, which can be deeper than
+// the per-trade compounded index the chain keeps.
+
+pub mod history;
+
+use std::collections::BTreeMap;
+
+use abi::{
+    max_drawdown, sharpe_ratio, Bracket, BracketLeg, BracketSpec, BracketStatus, EquityPoint, FormStrategy,
+    Order, OrderStatus, OrderType, SafetyConfig, Signal, Strategy, StrategyType, TimeInForce, ValidationStatus,
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+pub use history::{History, HistoryError, HistoryEvent, PriceTick};
+
+/// Share of equity per position when the strategy sets no risk percentage
+pub const DEFAULT_POSITION_FRACTION: f64 = 0.1;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BacktestConfig {
+    pub initial_capital: f64,
+    /// Checks applied to every entry order, as `ValidateOrder` does on chain
+    pub safety: Option<SafetyConfig>,
+}
+
+impl Default for BacktestConfig {
+    fn default() -> Self {
+        BacktestConfig {
+            initial_capital: 10_000.0,
+            safety: None,
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq)]
+pub enum BacktestError {
+    #[error("DSL strategies cannot be backtested natively")]
+    UnsupportedStrategy,
+    #[error("initial capital must be positive")]
+    InvalidCapital,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub enum ExitReason {
+    StopLoss,
+    TakeProfit,
+    /// Still open when the history ran out; marked to the last price
+    EndOfData,
+}
+
+/// A closed round trip
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BacktestTrade {
+    pub signal_id: u64,
+    pub token: String,
+    pub entry_order_id: u64,
+    pub exit_order_id: Option<u64>,
+    pub entry_price: f64,
+    pub exit_price: f64,
+    pub quantity: f64,
+    pub pnl: f64,
+    /// Return on the position (0.1 = +10%)
+    pub return_pct: f64,
+    pub opened_at: u64,
+    pub closed_at: u64,
+    pub exit_reason: ExitReason,
+}
+
+/// A signal the strategy accepted but could not trade
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct RejectedSignal {
+    pub signal_id: u64,
+    pub reason: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BacktestReport {
+    pub strategy_id: u64,
+    pub initial_capital: f64,
+    pub final_equity: f64,
+    /// Fractional return over the run (0.1 = +10%)
+    pub total_return: f64,
+    pub max_drawdown: f64,
+    pub sharpe_ratio: f64,
+    pub win_rate: f64,
+    pub total_trades: usize,
+    pub trades: Vec<BacktestTrade>,
+    pub equity_curve: Vec<EquityPoint>,
+    pub rejected_signals: Vec<RejectedSignal>,
+    /// Every simulated order, in the shape the contract would store it
+    pub orders: Vec<Order>,
+}
+
+/// Run `strategy` over `history`
+pub fn run(strategy: &Strategy, history: &History, config: &BacktestConfig) -> Result<BacktestReport, BacktestError> {
+    let StrategyType::Form(form) = &strategy.strategy_type else {
+        return Err(BacktestError::UnsupportedStrategy);
+    };
+    if !config.initial_capital.is_finite() || config.initial_capital <= 0.0 {
+        return Err(BacktestError::InvalidCapital);
+    }
+
+    let events = history.events();
+    let mut simulator = Simulator::new(strategy, form, config, events.first().map_or(0, HistoryEvent::timestamp));
+    for event in &events {
+        match event {
+            HistoryEvent::Price(tick) => simulator.on_price(tick),
+            HistoryEvent::Signal(signal) => simulator.on_signal(signal),
+        }
+    }
+    Ok(simulator.finish(events.last().map_or(0, HistoryEvent::timestamp)))
+}
+
+/// A filled entry and its live bracket
+struct Position {
+    entry: Order,
+    bracket: Bracket,
+}
+
+struct Simulator<'a> {
+    strategy: &'a Strategy,
+    form: &'a FormStrategy,
+    safety: Option<&'a SafetyConfig>,
+    initial_capital: f64,
+    cash: f64,
+    order_counter: u64,
+    orders: BTreeMap<u64, Order>,
+    positions: BTreeMap<u64, Position>, // entry order id -> position
+    last_price: BTreeMap<String, f64>,  // upper-cased token -> price
+    trades: Vec<BacktestTrade>,
+    equity_curve: Vec<EquityPoint>,
+    rejected_signals: Vec<RejectedSignal>,
+}
+
+fn token_key(token: &str) -> String {
+    token.trim().to_ascii_uppercase()
+}
+
+impl<'a> Simulator<'a> {
+    fn new(strategy: &'a Strategy, form: &'a FormStrategy, config: &'a BacktestConfig, start: u64) -> Self {
+        Simulator {
+            strategy,
+            form,
+            safety: config.safety.as_ref(),
+            initial_capital: config.initial_capital,
+            cash: config.initial_capital,
+            order_counter: 0,
+            orders: BTreeMap::new(),
+            positions: BTreeMap::new(),
+            last_price: BTreeMap::new(),
+            trades: Vec::new(),
+            equity_curve: vec![EquityPoint { timestamp: start, equity: config.initial_capital }],
+            rejected_signals: Vec::new(),
+        }
+    }
+
+    fn next_order_id(&mut self) -> u64 {
+        self.order_counter += 1;
+        self.order_counter
+    }
+
+    /// Cash plus open positions marked to their latest price
+    fn equity(&self) -> f64 {
+        self.cash + self.exposure()
+    }
+
+    fn exposure(&self) -> f64 {
+        self.positions
+            .values()
+            .map(|position| position.entry.quantity * self.mark_price(&position.entry))
+            .sum()
+    }
+
+    fn mark_price(&self, entry: &Order) -> f64 {
+        self.last_price
+            .get(&token_key(&entry.token))
+            .copied()
+            .or(entry.fill_price)
+            .unwrap_or(0.0)
+    }
+
+    /// Fire any bracket leg the tick reaches. Triggered legs fill at the
+    /// tick price, so a gap through a stop is filled at the gapped price.
+    fn on_price(&mut self, tick: &PriceTick) {
+        if !tick.price.is_finite() || tick.price <= 0.0 {
+            return;
+        }
+        let token = token_key(&tick.token);
+        self.last_price.insert(token.clone(), tick.price);
+
+        let entry_ids: Vec<u64> = self
+            .positions
+            .iter()
+            .filter(|(_, position)| token_key(&position.entry.token) == token)
+            .map(|(id, _)| *id)
+            .collect();
+        for entry_id in entry_ids {
+            let bracket = &self.positions[&entry_id].bracket;
+            let triggered = [bracket.stop_loss_order_id, bracket.take_profit_order_id]
+                .into_iter()
+                .find(|id| self.orders[id].is_triggered_at(tick.price));
+            if let Some(order_id) = triggered {
+                self.fill_bracket_leg(entry_id, order_id, tick.price, tick.timestamp);
+            }
+        }
+
+        let equity = self.equity();
+        self.equity_curve.push(EquityPoint { timestamp: tick.timestamp, equity });
+    }
+
+    fn on_signal(&mut self, signal: &Signal) {
+        let token = token_key(&signal.token);
+        let Some(price) = self.last_price.get(&token).copied().or(signal.entry_price) else {
+            return;
+        };
+        if !self.form.accepts_signal(signal, price) {
+            return;
+        }
+        // One position per token at a time
+        if self.positions.values().any(|position| token_key(&position.entry.token) == token) {
+            return;
+        }
+
+        let quantity = self.position_notional() / price;
+        if !quantity.is_finite() || quantity <= 0.0 {
+            self.reject(signal, "no capital or exposure left");
+            return;
+        }
+
+        // Signal targets take precedence over the form's percentages, as
+        // for orders created from a signal
+        let spec = BracketSpec::from_signal(signal)
+            .filter(|spec| spec.is_valid(false))
+            .unwrap_or_else(|| BracketSpec::from_form(self.form, price));
+        if !spec.is_valid(false) {
+            self.reject(signal, "invalid bracket");
+            return;
+        }
+
+        let mut entry = Order {
+            id: self.next_order_id(),
+            strategy_id: self.strategy.id,
+            signal_id: signal.id,
+            order_type: OrderType::Buy,
+            token: signal.token.clone(),
+            quantity,
+            status: OrderStatus::Pending,
+            tx_hash: None,
+            fill_price: None,
+            created_at: signal.timestamp,
+            filled_at: None,
+            time_in_force: TimeInForce::GoodTillCancelled,
+            expires_at: None,
+            bracket: Some(spec.clone()),
+            parent_order_id: None,
+            trigger_price: None,
+        };
+
+        if let Some(safety) = self.safety {
+            if let ValidationStatus::Rejected { reason } = safety.check_order(&entry).status {
+                entry.status = OrderStatus::Failed;
+                self.orders.insert(entry.id, entry);
+                self.reject(signal, &reason);
+                return;
+            }
+        }
+
+        entry.apply_fill(format!("backtest:{}", entry.id), price, signal.timestamp);
+        self.cash -= quantity * price;
+        self.orders.insert(entry.id, entry.clone());
+        self.open_bracket(entry, spec, signal.timestamp);
+    }
+
+    /// Size from the strategy's risk percentage, capped by its max exposure
+    /// and by available cash
+    fn position_notional(&self) -> f64 {
+        let fraction = if self.strategy.risk_percentage > 0.0 {
+            self.strategy.risk_percentage / 100.0
+        } else {
+            DEFAULT_POSITION_FRACTION
+        };
+        let mut notional = self.equity() * fraction;
+        if self.strategy.max_exposure > 0.0 {
+            notional = notional.min(self.strategy.max_exposure - self.exposure());
+        }
+        notional.min(self.cash)
+    }
+
+    fn open_bracket(&mut self, entry: Order, spec: BracketSpec, now: u64) {
+        let stop_loss = entry.bracket_child(self.next_order_id(), BracketLeg::StopLoss, spec.stop_loss, now);
+        let take_profit = entry.bracket_child(self.next_order_id(), BracketLeg::TakeProfit, spec.take_profit, now);
+        let bracket = Bracket {
+            entry_order_id: entry.id,
+            stop_loss_order_id: stop_loss.id,
+            take_profit_order_id: take_profit.id,
+            status: BracketStatus::Active,
+            created_at: now,
+            closed_at: None,
+        };
+        self.orders.insert(stop_loss.id, stop_loss);
+        self.orders.insert(take_profit.id, take_profit);
+        self.positions.insert(entry.id, Position { entry, bracket });
+    }
+
+    /// One-cancels-other, as `RecordOrderFill` does for a bracket leg: fill
+    /// the leg, cancel its sibling
+    fn fill_bracket_leg(&mut self, entry_id: u64, order_id: u64, price: f64, now: u64) {
+        let Some((leg, sibling_id)) = self.positions.get(&entry_id).and_then(|p| p.bracket.leg_of(order_id)) else {
+            return;
+        };
+        let Some(position) = self.positions.remove(&entry_id) else {
+            return;
+        };
+        if let Some(order) = self.orders.get_mut(&order_id) {
+            order.apply_fill(format!("backtest:{}", order_id), price, now);
+        }
+        self.cancel_order(sibling_id);
+
+        let reason = match leg {
+            BracketLeg::StopLoss => ExitReason::StopLoss,
+            BracketLeg::TakeProfit => ExitReason::TakeProfit,
+        };
+        self.close(&position.entry, Some(order_id), price, now, reason);
+    }
+
+    fn cancel_order(&mut self, order_id: u64) {
+        if let Some(order) = self.orders.get_mut(&order_id) {
+            if order.status.is_open() {
+                order.status = OrderStatus::Cancelled;
+            }
+        }
+    }
+
+    fn close(&mut self, entry: &Order, exit_order_id: Option<u64>, exit_price: f64, now: u64, exit_reason: ExitReason) {
+        let entry_price = entry.fill_price.unwrap_or(exit_price);
+        let pnl = (exit_price - entry_price) * entry.quantity;
+        self.cash += exit_price * entry.quantity;
+        self.trades.push(BacktestTrade {
+            signal_id: entry.signal_id,
+            token: entry.token.clone(),
+            entry_order_id: entry.id,
+            exit_order_id,
+            entry_price,
+            exit_price,
+            quantity: entry.quantity,
+            pnl,
+            return_pct: (exit_price - entry_price) / entry_price,
+            opened_at: entry.filled_at.unwrap_or(entry.created_at),
+            closed_at: now,
+            exit_reason,
+        });
+    }
+
+    fn reject(&mut self, signal: &Signal, reason: &str) {
+        self.rejected_signals.push(RejectedSignal { signal_id: signal.id, reason: reason.to_string() });
+    }
+
+    /// Close what is still open at the last price, as `CloseBracketPosition`
+    /// would, and compute the summary metrics
+    fn finish(mut self, end: u64) -> BacktestReport {
+        let open: Vec<Position> = std::mem::take(&mut self.positions).into_values().collect();
+        let had_open = !open.is_empty();
+        for position in open {
+            self.cancel_order(position.bracket.stop_loss_order_id);
+            self.cancel_order(position.bracket.take_profit_order_id);
+            let price = self.mark_price(&position.entry);
+            self.close(&position.entry, None, price, end, ExitReason::EndOfData);
+        }
+        if had_open {
+            self.equity_curve.push(EquityPoint { timestamp: end, equity: self.cash });
+        }
+
+        let equity: Vec<f64> = self.equity_curve.iter().map(|point| point.equity).collect();
+        let returns: Vec<f64> = self.trades.iter().map(|trade| trade.return_pct).collect();
+        let wins = self.trades.iter().filter(|trade| trade.pnl > 0.0).count();
+        BacktestReport {
+            strategy_id: self.strategy.id,
+            initial_capital: self.initial_capital,
+            final_equity: self.cash,
+            total_return: (self.cash - self.initial_capital) / self.initial_capital,
+            max_drawdown: max_drawdown(&equity),
+            sharpe_ratio: sharpe_ratio(&returns),
+            win_rate: if self.trades.is_empty() { 0.0 } else { wins as f64 / self.trades.len() as f64 },
+            total_trades: self.trades.len(),
+            trades: self.trades,
+            equity_curve: self.equity_curve,
+            rejected_signals: self.rejected_signals,
+            orders: self.orders.into_values().collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,76 @@
+// Command-line backtester: replays a history file against a strategy and
+// prints the report as JSON.
+//
+//   backtest --strategy strategy.json --history history.json
+//   backtest --strategy strategy.json --prices prices.csv --signals signals.json
+//
+// Optional: --safety safety.json, --capital 10000
+
+use std::{collections::HashMap, fs, process};
+
+use backtest::{BacktestConfig, History};
+
+const USAGE: &str = "usage: backtest --strategy <file> (--history <file.json> | --prices <file.csv|file.json> --signals <file.json>) [--safety <file.json>] [--capital <amount>]";
+
+fn main() {
+    if let Err(error) = run() {
+        eprintln!("error: {}", error);
+        process::exit(1);
+    }
+}
+
+fn run() -> Result<(), String> {
+    let args = parse_args()?;
+    let read = |flag: &str| -> Result<Option<String>, String> {
+        args.get(flag)
+            .map(|path| fs::read_to_string(path).map_err(|e| format!("{}: {}", path, e)))
+            .transpose()
+    };
+
+    let strategy = read("--strategy")?.ok_or(USAGE)?;
+    let strategy = serde_json::from_str(&strategy).map_err(|e| format!("strategy: {}", e))?;
+
+    let history = match read("--history")? {
+        Some(history) => History::from_json(&history).map_err(|e| e.to_string())?,
+        None => {
+            let prices_path = args.get("--prices").ok_or(USAGE)?;
+            let prices = read("--prices")?.unwrap_or_default();
+            let prices = if prices_path.ends_with(".csv") {
+                History::prices_from_csv(&prices)
+            } else {
+                History::prices_from_json(&prices)
+            };
+            let signals = read("--signals")?.ok_or(USAGE)?;
+            History {
+                prices: prices.map_err(|e| e.to_string())?,
+                signals: History::signals_from_json(&signals).map_err(|e| e.to_string())?,
+            }
+        }
+    };
+
+    let mut config = BacktestConfig::default();
+    if let Some(safety) = read("--safety")? {
+        config.safety = Some(serde_json::from_str(&safety).map_err(|e| format!("safety: {}", e))?);
+    }
+    if let Some(capital) = args.get("--capital") {
+        config.initial_capital = capital.parse().map_err(|_| format!("invalid capital: {}", capital))?;
+    }
+
+    let report = backtest::run(&strategy, &history, &config).map_err(|e| e.to_string())?;
+    println!("{}", serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?);
+    Ok(())
+}
+
+/// `--flag value` pairs
+fn parse_args() -> Result<HashMap<String, String>, String> {
+    let mut args = HashMap::new();
+    let mut iter = std::env::args().skip(1);
+    while let Some(flag) = iter.next() {
+        if !flag.starts_with("--") {
+            return Err(USAGE.to_string());
+        }
+        let value = iter.next().ok_or(USAGE)?;
+        args.insert(flag, value);
+    }
+    Ok(args)
+}
@@ -0,0 +1,174 @@
+#[cfg(test)]
+mod backtest_tests {
+    use super::super::*;
+    use abi::{Sentiment, StrategyFees, StrategySource, StrategyVisibility};
+
+    fn strategy() -> Strategy {
+        Strategy {
+            id: 7,
+            owner: "owner".to_string(),
+            name: "SOL dip buyer".to_string(),
+            strategy_type: StrategyType::Form(FormStrategy {
+                token_pair: "SOL/USDC".to_string(),
+                buy_price: 0.0,
+                sell_target: 0.0,
+                trailing_stop_pct: 0.0,
+                take_profit_pct: 10.0,
+                max_loss_pct: 5.0,
+            }),
+            active: true,
+            created_at: 0,
+            version: 1,
+            updated_at: None,
+            source: StrategySource::Manual { author: "owner".to_string() },
+            risk_percentage: 50.0,
+            max_exposure: 0.0,
+            slippage_bps: 50,
+            fees: StrategyFees::default(),
+            follower_count: 0,
+            risk_version: 1,
+            forked_from: None,
+            fork_count: 0,
+            visibility: StrategyVisibility::Public,
+            rules_commitment: None,
+        }
+    }
+
+    fn signal(id: u64, timestamp: u64, sentiment: Sentiment) -> Signal {
+        Signal {
+            id,
+            influencer: "trader".to_string(),
+            token: "sol".to_string(),
+            contract: "So11111111111111111111111111111111111111112".to_string(),
+            sentiment,
+            confidence: 0.9,
+            timestamp,
+            tweet_url: format!("https://twitter.com/trader/status/{}", id),
+            entry_price: None,
+            stop_loss: None,
+            take_profit: None,
+            position_size: None,
+            leverage: None,
+            platform: None,
+        }
+    }
+
+    fn tick(timestamp: u64, price: f64) -> PriceTick {
+        PriceTick { timestamp, token: "SOL".to_string(), price }
+    }
+
+    #[test]
+    fn test_take_profit_then_stop_loss() {
+        let history = History {
+            prices: vec![tick(1, 100.0), tick(3, 105.0), tick(4, 111.0), tick(5, 100.0), tick(7, 94.0)],
+            signals: vec![
+                signal(1, 1, Sentiment::Bullish),
+                signal(2, 2, Sentiment::Bearish),
+                signal(3, 5, Sentiment::Bullish),
+            ],
+        };
+        let report = run(&strategy(), &history, &BacktestConfig::default()).unwrap();
+
+        assert_eq!(report.total_trades, 2);
+        let (win, loss) = (&report.trades[0], &report.trades[1]);
+        assert_eq!(win.exit_reason, ExitReason::TakeProfit);
+        assert_eq!((win.entry_price, win.exit_price), (100.0, 111.0));
+        assert_eq!(loss.exit_reason, ExitReason::StopLoss);
+        assert_eq!(loss.exit_price, 94.0);
+        assert_eq!(report.win_rate, 0.5);
+
+        // 50% of 10_000 at +11%, then 50% of 10_550 at -6%
+        let expected = 10_000.0 + 550.0 - 5_275.0 * 0.06;
+        assert!((report.final_equity - expected).abs() < 1e-6);
+        assert!(report.max_drawdown > 0.0);
+
+        // Same per-trade Sharpe the chain reports for these trades
+        let mut stats = abi::PerformanceStats::default();
+        for trade in &report.trades {
+            stats.record_trade(&abi::ClosedTrade {
+                strategy_id: 7,
+                entry_order_id: trade.entry_order_id,
+                exit_order_id: trade.exit_order_id.unwrap_or(0),
+                quantity: trade.quantity,
+                entry_price: trade.entry_price,
+                exit_price: trade.exit_price,
+                is_short: false,
+                opened_at: trade.opened_at,
+                closed_at: trade.closed_at,
+            });
+        }
+        assert!((report.sharpe_ratio - stats.metrics().sharpe_ratio).abs() < 1e-9);
+
+        // Filled legs cancel their sibling, as on chain
+        let statuses: Vec<OrderStatus> = report.orders.iter().map(|order| order.status.clone()).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                OrderStatus::Filled,
+                OrderStatus::Cancelled,
+                OrderStatus::Filled,
+                OrderStatus::Filled,
+                OrderStatus::Filled,
+                OrderStatus::Cancelled,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_open_position_closes_at_end_of_data() {
+        let history = History {
+            prices: vec![tick(1, 100.0), tick(2, 103.0)],
+            signals: vec![signal(1, 1, Sentiment::Bullish)],
+        };
+        let report = run(&strategy(), &history, &BacktestConfig::default()).unwrap();
+        assert_eq!(report.trades[0].exit_reason, ExitReason::EndOfData);
+        assert_eq!(report.trades[0].exit_price, 103.0);
+        assert_eq!(report.equity_curve.last().unwrap().equity, report.final_equity);
+    }
+
+    #[test]
+    fn test_safety_config_rejects_entries() {
+        let history = History {
+            prices: vec![tick(1, 100.0)],
+            signals: vec![signal(1, 1, Sentiment::Bullish)],
+        };
+        let config = BacktestConfig {
+            safety: Some(SafetyConfig {
+                id: 0,
+                owner: "owner".to_string(),
+                max_position_per_token: 1.0,
+                max_total_exposure: 0.0,
+                max_slippage_bps: 50,
+                max_loss_percentage: 10.0,
+                require_stop_loss: true,
+                fail_safe_enabled: false,
+                min_balance_required: 0.0,
+            }),
+            ..BacktestConfig::default()
+        };
+        let report = run(&strategy(), &history, &config).unwrap();
+        assert_eq!(report.total_trades, 0);
+        assert_eq!(report.rejected_signals[0].signal_id, 1);
+        assert_eq!(report.orders[0].status, OrderStatus::Failed);
+        assert_eq!(report.final_equity, 10_000.0);
+    }
+
+    #[test]
+    fn test_dsl_strategies_are_unsupported() {
+        let dsl = Strategy { strategy_type: StrategyType::DSL("buy when bullish".to_string()), ..strategy() };
+        assert_eq!(
+            run(&dsl, &History::default(), &BacktestConfig::default()).unwrap_err(),
+            BacktestError::UnsupportedStrategy
+        );
+    }
+
+    #[test]
+    fn test_prices_from_csv() {
+        let prices = History::prices_from_csv("timestamp,token,price\n1,SOL,100.5\n\n2, SOL ,101\n").unwrap();
+        assert_eq!(prices, vec![tick(1, 100.5), tick(2, 101.0)]);
+        assert!(matches!(
+            History::prices_from_csv("1,SOL"),
+            Err(HistoryError::Csv { line: 1, .. })
+        ));
+    }
+}
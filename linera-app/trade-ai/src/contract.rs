@@ -2,15 +2,23 @@
 
 mod state;
 
+use std::collections::BTreeMap;
+
 use linera_sdk::{Contract, ContractRuntime};
 use linera_sdk::abi::WithContractAbi;
 use linera_sdk::views::RootView;
-use linera_sdk::linera_base_types::StreamName;
-use abi::{LineraTradeAbi, Event, Operation, Order, OrderStatus, Signal, Strategy, DEXOrder, StrategyFollower, TradeReplication, ReplicationStatus, SafetyConfig, ValidatedOrder, ValidationStatus, PredictionMarket, StrategyMarketLink, StrategyVersion, MicrochainProfile};
+use linera_sdk::linera_base_types::{AccountSignature, Amount, ApplicationPermissions, ChainId, ChainOwnership, StreamName};
+use abi::{LineraTradeAbi, BatchItem, Event, Message, Operation, SignalError, SignalPolicy, OutcomeHorizon, TargetHit, SignalLifecycle, SignalOutcome, InfluencerStats, OperationResponse, OrderFill, DEFAULT_MAX_BATCH_SIZE, DEFAULT_IMMEDIATE_ORDER_WINDOW_MICROS, ClosedTrade, EquityPoint, PerformanceStats, MAX_EQUITY_POINTS, FollowerFeeAccount, FeeKind, FeeLedgerEntry, Order, OrderStatus, Signal, Strategy, StrategyOverrides, ForkOrigin, StrategyType, StrategyVisibility, StrategySource, RulesReveal, rules_commitment, CuratorReview, is_valid_review, DEXOrder, StrategyFollower, TradeReplication, ReplicationStatus, ReplicationPolicy, is_valid_follow_sizing, SafetyConfig, SafetyCheck, ValidatedOrder, PredictionMarket, StrategyMarketLink, StrategyVersion, MicrochainProfile, WalletLinkProof, LegFill, ExecutionMode, Bracket, BracketLeg, BracketStatus, ProfileVisibility, TimeInForce, is_expired};
 use self::state::LineraTradeState;
 
 linera_sdk::contract!(LineraTradeContract);
 
+/// Upper bound on orders scanned per `SweepExpiredOrders` call
+const MAX_EXPIRY_SWEEP: u32 = 500;
+
+/// Upper bound on open signals evaluated per `ReportTokenPrice` call
+const MAX_PRICE_REPORT_SIGNALS: usize = 200;
+
 pub struct LineraTradeContract {
     state: LineraTradeState,
     runtime: ContractRuntime<Self>,
@@ -23,7 +31,7 @@ impl WithContractAbi for LineraTradeContract {
 impl Contract for LineraTradeContract {
     type Parameters = ();
     type InstantiationArgument = ();
-    type Message = ();
+    type Message = Message;
     type EventValue = Event;
 
     async fn load(runtime: ContractRuntime<Self>) -> Self {
@@ -44,17 +52,70 @@ impl Contract for LineraTradeContract {
         self.state.order_counter.set(0);
         self.state.dex_order_counter.set(0);
         self.state.market_counter.set(0);
+        self.state.max_batch_size.set(DEFAULT_MAX_BATCH_SIZE);
+        self.state.admin.set(self.runtime.authenticated_signer().map(|owner| owner.to_string()));
     }
 
-    async fn execute_operation(&mut self, operation: Operation) -> u64 {
-        match operation {
-            Operation::SubmitSignal { signal } => {
-                self.submit_signal(signal).await;
-                0
+    async fn execute_operation(&mut self, operation: Operation) -> OperationResponse {
+        self.dispatch_operation(operation).await
+    }
+
+    async fn execute_message(&mut self, message: Message) {
+        let Some(origin) = self.runtime.message_origin_chain_id() else {
+            return;
+        };
+        if self.runtime.message_is_bouncing() == Some(true) {
+            match message {
+                // A profile chain that could not be set up leaves the profile here
+                Message::InitProfileChain { profile, .. } => self.reclaim_profile(profile.id).await,
+                // ...and its trades are then recorded here too
+                Message::RecordProfileTrade { owner, trade } => self.record_profile_trade(&owner, &trade).await,
+                _ => {}
             }
-            Operation::CreateStrategy { strategy } => {
-                self.create_strategy(strategy).await;
-                0
+            return;
+        }
+        match message {
+            Message::InitProfileChain { profile, admin } => {
+                self.init_profile_chain(origin, profile, admin).await;
+            }
+            Message::ProfileUpdated { profile } => {
+                self.sync_profile(origin, profile).await;
+            }
+            Message::ProfileTradeRecorded { owner, stats, point } => {
+                self.sync_profile_trade(origin, owner, stats, point).await;
+            }
+            Message::ProfileDeleted { profile_id } => {
+                self.sync_profile_deletion(origin, profile_id).await;
+            }
+            Message::RecordProfileTrade { owner, trade } => {
+                if *self.state.registry_chain.get() == Some(origin) {
+                    self.record_profile_trade(&owner, &trade).await;
+                }
+            }
+        }
+    }
+}
+
+impl LineraTradeContract {
+    async fn dispatch_operation(&mut self, operation: Operation) -> OperationResponse {
+        let id = match operation {
+            Operation::SubmitSignal { signal, client_request_id } => {
+                let key = self.client_request_key("signal", &client_request_id);
+                if let Some(id) = self.replayed_request(&key).await {
+                    return OperationResponse::Id(id);
+                }
+                let id = self.submit_signal(signal).await;
+                self.remember_request(key, id);
+                id
+            }
+            Operation::CreateStrategy { strategy, client_request_id } => {
+                let key = self.client_request_key("strategy", &client_request_id);
+                if let Some(id) = self.replayed_request(&key).await {
+                    return OperationResponse::Id(id);
+                }
+                let id = self.create_strategy(strategy).await;
+                self.remember_request(key, id);
+                id
             }
             Operation::ActivateStrategy { strategy_id } => {
                 self.activate_strategy(strategy_id).await;
@@ -64,9 +125,14 @@ impl Contract for LineraTradeContract {
                 self.deactivate_strategy(strategy_id).await;
                 0
             }
-            Operation::CreateOrder { order } => {
-                self.create_order(order).await;
-                0
+            Operation::CreateOrder { order, client_request_id } => {
+                let key = self.client_request_key("order", &client_request_id);
+                if let Some(id) = self.replayed_request(&key).await {
+                    return OperationResponse::Id(id);
+                }
+                let id = self.create_order(order).await;
+                self.remember_request(key, id);
+                id
             }
             Operation::RecordOrderFill {
                 order_id,
@@ -78,10 +144,19 @@ impl Contract for LineraTradeContract {
                     .await;
                 0
             }
-            Operation::CreateDEXOrder { order } => {
-                self.create_dex_order(order).await;
+            Operation::RecordOrderFailure { order_id, reason } => {
+                self.record_order_failure(order_id, reason).await;
                 0
             }
+            Operation::CreateDEXOrder { order, client_request_id } => {
+                let key = self.client_request_key("dex_order", &client_request_id);
+                if let Some(id) = self.replayed_request(&key).await {
+                    return OperationResponse::Id(id);
+                }
+                let id = self.create_dex_order(order).await;
+                self.remember_request(key, id);
+                id
+            }
             Operation::ExecuteDEXOrder { order_id, tx_signature } => {
                 self.execute_dex_order(order_id, tx_signature).await;
                 0
@@ -91,20 +166,50 @@ impl Contract for LineraTradeContract {
                 allocation_percentage,
                 max_position_size,
                 auto_follow,
+                policy,
             } => {
-                self.follow_strategy(strategy_id, allocation_percentage, max_position_size, auto_follow).await;
+                self.follow_strategy(strategy_id, allocation_percentage, max_position_size, auto_follow, policy.unwrap_or_default()).await;
                 0
             }
             Operation::UnfollowStrategy { strategy_id } => {
                 self.unfollow_strategy(strategy_id).await;
                 0
             }
+            Operation::UpdateFollow {
+                strategy_id,
+                allocation_percentage,
+                max_position_size,
+                auto_follow,
+                policy,
+            } => {
+                self.update_follow(strategy_id, allocation_percentage, max_position_size, auto_follow, policy).await;
+                0
+            }
+            Operation::PauseFollow { strategy_id } => {
+                self.set_follow_paused(strategy_id, true).await;
+                0
+            }
+            Operation::ResumeFollow { strategy_id } => {
+                self.set_follow_paused(strategy_id, false).await;
+                0
+            }
+            Operation::ConsentStrategyVersion { strategy_id } => {
+                self.consent_strategy_version(strategy_id).await;
+                0
+            }
             Operation::ReplicateTrade {
                 original_order_id,
                 follower_id,
                 scale_factor,
             } => {
-                self.replicate_trade(original_order_id, follower_id, scale_factor).await;
+                self.replicate_trade(original_order_id, follower_id, scale_factor).await
+            }
+            Operation::AccrueFollowerFees { strategy_id, follower_id } => {
+                self.accrue_follower_fees(strategy_id, follower_id).await;
+                0
+            }
+            Operation::ClaimFollowerFees { strategy_id, follower_id } => {
+                self.claim_follower_fees(strategy_id, follower_id).await;
                 0
             }
             // Safety & Validation Operations (Phase 1)
@@ -142,14 +247,28 @@ impl Contract for LineraTradeContract {
                 self.update_strategy(strategy, change_reason).await;
                 0
             }
+            Operation::RollbackStrategy { strategy_id, to_version } => {
+                self.rollback_strategy(strategy_id, to_version).await;
+                0
+            }
+            Operation::ForkStrategy { strategy_id, overrides } => self.fork_strategy(strategy_id, overrides).await,
+            Operation::RevealStrategyRules { strategy_id, version, strategy_type, salt } => {
+                self.reveal_strategy_rules(strategy_id, version, strategy_type, salt).await;
+                0
+            }
             Operation::GetStrategyHistory { strategy_id } => {
                 // This is a read operation, handled by service
                 strategy_id
             }
             // Execution Engine Operations (Phase 3)
-            Operation::CreateMultiHopOrder { order } => {
-                self.create_multi_hop_order(order).await;
-                0
+            Operation::CreateMultiHopOrder { order, client_request_id } => {
+                let key = self.client_request_key("dex_order", &client_request_id);
+                if let Some(id) = self.replayed_request(&key).await {
+                    return OperationResponse::Id(id);
+                }
+                let id = self.create_multi_hop_order(order).await;
+                self.remember_request(key, id);
+                id
             }
             Operation::CheckConditionalOrders => {
                 self.check_conditional_orders().await;
@@ -163,146 +282,1277 @@ impl Contract for LineraTradeContract {
                 self.cancel_conditional_order(order_id).await;
                 0
             }
-            Operation::CreateMicrochainProfile { name, wallet, chains, visibility } => {
-                self.create_microchain_profile(name, wallet, chains, visibility).await;
+            Operation::CreateSplitRouteOrder { order, client_request_id } => {
+                let key = self.client_request_key("dex_order", &client_request_id);
+                if let Some(id) = self.replayed_request(&key).await {
+                    return OperationResponse::Id(id);
+                }
+                let id = self.create_split_route_order(order).await;
+                self.remember_request(key, id);
+                id
+            }
+            Operation::ExecuteSplitRouteOrder { order_id, leg_fills } => {
+                self.execute_split_route_order(order_id, leg_fills).await;
+                0
+            }
+            Operation::ExecuteOrderSlice { order_id, slice_index, tx_signature, output_amount } => {
+                self.execute_order_slice(order_id, slice_index, tx_signature, output_amount).await;
+                0
+            }
+            Operation::PauseDEXOrder { order_id } => {
+                self.set_dex_order_paused(order_id, true).await;
+                0
+            }
+            Operation::ResumeDEXOrder { order_id } => {
+                self.set_dex_order_paused(order_id, false).await;
+                0
+            }
+            Operation::CancelDEXOrderRemainder { order_id } => {
+                self.cancel_dex_order_remainder(order_id).await;
+                0
+            }
+            Operation::CloseBracketPosition { entry_order_id } => {
+                self.close_bracket_position(entry_order_id).await;
+                0
+            }
+            Operation::SweepExpiredOrders { limit } => {
+                self.sweep_expired_orders(limit).await;
+                0
+            }
+            Operation::RecordSignalPrice { signal_id, horizon, price } => {
+                self.record_signal_price(signal_id, horizon, price).await;
+                0
+            }
+            Operation::RecordSignalTargetHit { signal_id, hit } => {
+                self.record_signal_target_hit(signal_id, hit).await;
+                0
+            }
+            Operation::ReportTokenPrice { contract, price } => {
+                self.report_token_price(contract, price).await;
+                0
+            }
+            Operation::SweepExpiredSignals { limit } => {
+                self.sweep_expired_signals(limit).await;
+                0
+            }
+            Operation::AuthorizeSignalSubmitter { submitter } => {
+                self.authorize_signal_submitter(submitter).await;
+                0
+            }
+            Operation::RevokeSignalSubmitter { submitter } => {
+                self.revoke_signal_submitter(submitter).await;
+                0
+            }
+            Operation::AddCurator { curator } => {
+                self.add_curator(curator).await;
+                0
+            }
+            Operation::RemoveCurator { curator } => {
+                self.remove_curator(curator).await;
+                0
+            }
+            Operation::RateStrategy { strategy_id, rating, review } => {
+                self.rate_strategy(strategy_id, rating, review).await;
+                0
+            }
+            Operation::UnrateStrategy { strategy_id } => {
+                self.unrate_strategy(strategy_id).await;
+                0
+            }
+            Operation::SetSignalPolicy { policy } => {
+                self.set_signal_policy(policy);
+                0
+            }
+            Operation::SetMaxBatchSize { max_batch_size } => {
+                self.set_max_batch_size(max_batch_size).await;
+                0
+            }
+            Operation::SubmitSignalBatch { signals } => {
+                return OperationResponse::Batch(self.submit_signal_batch(signals).await);
+            }
+            Operation::CreateOrderBatch { orders } => {
+                return OperationResponse::Batch(self.create_order_batch(orders).await);
+            }
+            Operation::RecordOrderFillBatch { fills } => {
+                return OperationResponse::Batch(self.record_order_fill_batch(fills).await);
+            }
+            Operation::CreateMicrochainProfile { name, wallet, chains, visibility, signature } => {
+                self.create_microchain_profile(name, wallet, chains, visibility, signature).await;
+                0
+            }
+            Operation::UpdateMicrochainProfile { profile_id, name, chains, visibility } => {
+                self.update_microchain_profile(profile_id, name, chains, visibility).await;
                 0
             }
+            Operation::LinkWallet { profile_id, wallet, signature } => {
+                self.link_wallet(profile_id, wallet, signature).await;
+                0
+            }
+            Operation::UnlinkWallet { profile_id, wallet } => {
+                self.unlink_wallet(profile_id, wallet).await;
+                0
+            }
+            Operation::DeleteMicrochainProfile { profile_id } => {
+                self.delete_microchain_profile(profile_id).await;
+                0
+            }
+            Operation::SetRegistryMode { enabled } => {
+                self.set_registry_mode(enabled);
+                0
+            }
+            Operation::SetImmediateOrderWindow { window_micros } => {
+                self.set_immediate_order_window(window_micros);
+                0
+            }
+            Operation::SetProfileChainBalance { balance } => {
+                self.set_profile_chain_balance(balance);
+                0
+            }
+        };
+        OperationResponse::Id(id)
+    }
+
+    // ============================================
+    // SIGNAL ANTI-SPAM METHODS
+    // ============================================
+
+    /// Authorisation, rate limit, structural validation and dedupe for a
+    /// submitted signal. Counts the submission against the rate limit.
+    async fn check_signal(&mut self, signal: &Signal) -> Result<(), SignalError> {
+        let submitter = self.signal_submitter().await?;
+        self.check_signal_content(signal).await?;
+        self.record_submissions(&submitter, 1).await
+    }
+
+    /// The authenticated signer, if it may submit signals
+    async fn signal_submitter(&mut self) -> Result<String, SignalError> {
+        let submitter = self.runtime.authenticated_signer().map(|owner| owner.to_string());
+        let authorised = match submitter {
+            Some(ref submitter) => {
+                self.is_admin()
+                    || self.state.signal_submitters.contains_key(submitter).await.unwrap_or(false)
+            }
+            None => false,
+        };
+        submitter.filter(|_| authorised).ok_or(SignalError::UnauthorisedSubmitter)
+    }
+
+    /// Structural validation and dedupe against stored signals
+    async fn check_signal_content(&mut self, signal: &Signal) -> Result<(), SignalError> {
+        signal.validate()?;
+
+        if !signal.tweet_url.is_empty() {
+            if let Ok(Some(existing)) = self.state.signal_by_tweet.get(&signal.tweet_url).await {
+                return Err(SignalError::DuplicateTweet(existing));
+            }
+        }
+        let now = self.runtime.system_time().micros();
+        let policy = self.state.signal_policy.get().clone();
+        let call_key = format!("{}:{}", signal.influencer, signal.contract);
+        if let Ok(Some((existing, submitted_at))) = self.state.last_call.get(&call_key).await {
+            if now < submitted_at.saturating_add(policy.duplicate_window_micros) {
+                return Err(SignalError::DuplicateCall(existing));
+            }
         }
+        Ok(())
     }
 
-    async fn execute_message(&mut self, _message: ()) {}
-}
+    /// Count `count` submissions against the submitter's rate limit, all or none
+    async fn record_submissions(&mut self, submitter: &String, count: u32) -> Result<(), SignalError> {
+        let now = self.runtime.system_time().micros();
+        let policy = self.state.signal_policy.get().clone();
+        let mut window = self.state.submitter_windows.get(submitter).await.ok().flatten().unwrap_or_default();
+        if !window.try_record_many(now, &policy, count) {
+            return Err(SignalError::RateLimited(policy.max_signals_per_window));
+        }
+        let _ = self.state.submitter_windows.insert(submitter, window);
+        Ok(())
+    }
 
-impl LineraTradeContract {
-    async fn submit_signal(&mut self, mut signal: Signal) {
-        // Validate signal
-        if signal.confidence < 0.0 || signal.confidence > 1.0 {
+    async fn authorize_signal_submitter(&mut self, submitter: String) {
+        if !self.is_admin() {
             return;
         }
+        let _ = self.state.signal_submitters.insert(&submitter, ());
 
-        // Generate ID
-        let id = *self.state.signal_counter.get() + 1;
-        signal.id = id;
-        self.state.signal_counter.set(id);
+        let event = Event::SignalSubmitterAuthorized { submitter };
+        let stream_name = StreamName::from(bcs::to_bytes(&"signal_submitter_authorized").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
 
-        // Store signal
-        let _ = self.state.signals.insert(&id, signal.clone());
+    async fn revoke_signal_submitter(&mut self, submitter: String) {
+        if !self.is_admin() || !self.state.signal_submitters.contains_key(&submitter).await.unwrap_or(false) {
+            return;
+        }
+        let _ = self.state.signal_submitters.remove(&submitter);
 
-        // Emit event
-        let event = Event::SignalReceived { signal };
-        let stream_name = StreamName::from(bcs::to_bytes(&"signal_received").unwrap());
+        let event = Event::SignalSubmitterRevoked { submitter };
+        let stream_name = StreamName::from(bcs::to_bytes(&"signal_submitter_revoked").unwrap());
         self.runtime.emit(stream_name, &event);
     }
 
-    async fn create_strategy(&mut self, mut strategy: Strategy) {
-        // Generate ID
-        let id = *self.state.strategy_counter.get() + 1;
-        strategy.id = id;
-        self.state.strategy_counter.set(id);
+    // ============================================
+    // CURATION METHODS
+    // ============================================
 
-        // Store strategy
-        let owner = strategy.owner.clone();
-        let _ = self.state.strategies.insert(&id, strategy);
+    async fn add_curator(&mut self, curator: String) {
+        if !self.is_admin() || self.state.curators.contains_key(&curator).await.unwrap_or(true) {
+            return;
+        }
+        let _ = self.state.curators.insert(&curator, ());
 
-        // Emit event
-        let event = Event::StrategyCreated {
-            strategy_id: id,
-            owner,
-        };
-        let stream_name = StreamName::from(bcs::to_bytes(&"strategy_created").unwrap());
+        let event = Event::CuratorAdded { curator };
+        let stream_name = StreamName::from(bcs::to_bytes(&"curator_added").unwrap());
         self.runtime.emit(stream_name, &event);
     }
 
-    async fn activate_strategy(&mut self, strategy_id: u64) {
-        if let Ok(Some(mut strategy)) = self.state.strategies.get(&strategy_id).await {
-            strategy.active = true;
-            let _ = self.state.strategies.insert(&strategy_id, strategy);
+    async fn remove_curator(&mut self, curator: String) {
+        if !self.is_admin() || !self.state.curators.contains_key(&curator).await.unwrap_or(false) {
+            return;
+        }
+        let _ = self.state.curators.remove(&curator);
 
-            let event = Event::StrategyActivated { strategy_id };
-            let stream_name = StreamName::from(bcs::to_bytes(&"strategy_activated").unwrap());
-            self.runtime.emit(stream_name, &event);
+        let strategy_ids = match self.state.curator_reviews.try_load_entry(&curator).await {
+            Ok(Some(reviewed)) => reviewed.indices().await.unwrap_or_default(),
+            _ => Vec::new(),
+        };
+        for strategy_id in strategy_ids {
+            self.withdraw_rating(strategy_id, &curator).await;
         }
+
+        let event = Event::CuratorRemoved { curator };
+        let stream_name = StreamName::from(bcs::to_bytes(&"curator_removed").unwrap());
+        self.runtime.emit(stream_name, &event);
     }
 
-    async fn deactivate_strategy(&mut self, strategy_id: u64) {
-        if let Ok(Some(mut strategy)) = self.state.strategies.get(&strategy_id).await {
-            strategy.active = false;
-            let _ = self.state.strategies.insert(&strategy_id, strategy);
+    async fn rate_strategy(&mut self, strategy_id: u64, rating: u8, review: String) {
+        let Some(curator) = self.runtime.authenticated_signer().map(|owner| owner.to_string()) else {
+            return;
+        };
+        if !is_valid_review(rating, &review) || !self.state.curators.contains_key(&curator).await.unwrap_or(false) {
+            return;
+        }
+        let strategy_version = match self.state.strategies.get(&strategy_id).await {
+            Ok(Some(strategy)) if strategy.owner != curator => strategy.version,
+            _ => return,
+        };
 
-            let event = Event::StrategyDeactivated { strategy_id };
-            let stream_name = StreamName::from(bcs::to_bytes(&"strategy_deactivated").unwrap());
-            self.runtime.emit(stream_name, &event);
+        // A re-rating replaces the curator's previous rating
+        self.withdraw_rating(strategy_id, &curator).await;
+        let review = CuratorReview {
+            strategy_id,
+            curator: curator.clone(),
+            rating,
+            review,
+            strategy_version,
+            reviewed_at: self.runtime.system_time().micros(),
+        };
+        if let Ok(reviews) = self.state.strategy_reviews.load_entry_mut(&strategy_id).await {
+            let _ = reviews.insert(&curator, review);
         }
-    }
+        if let Ok(reviewed) = self.state.curator_reviews.load_entry_mut(&curator).await {
+            let _ = reviewed.insert(&strategy_id);
+        }
+        let mut ratings = self.state.strategy_ratings.get(&strategy_id).await.ok().flatten().unwrap_or_default();
+        ratings.add(rating);
+        let _ = self.state.strategy_ratings.insert(&strategy_id, ratings);
 
-    async fn create_order(&mut self, mut order: Order) {
-        // Generate ID
-        let id = *self.state.order_counter.get() + 1;
-        order.id = id;
-        self.state.order_counter.set(id);
+        let event = Event::StrategyRated { strategy_id, curator, rating };
+        let stream_name = StreamName::from(bcs::to_bytes(&"strategy_rated").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
 
-        // Store order
-        let _ = self.state.orders.insert(&id, order.clone());
+    async fn unrate_strategy(&mut self, strategy_id: u64) {
+        let Some(curator) = self.runtime.authenticated_signer().map(|owner| owner.to_string()) else {
+            return;
+        };
+        if !self.withdraw_rating(strategy_id, &curator).await {
+            return;
+        }
 
-        // Emit event
-        let event = Event::OrderCreated { order };
-        let stream_name = StreamName::from(bcs::to_bytes(&"order_created").unwrap());
+        let event = Event::StrategyUnrated { strategy_id, curator };
+        let stream_name = StreamName::from(bcs::to_bytes(&"strategy_unrated").unwrap());
         self.runtime.emit(stream_name, &event);
     }
 
-    async fn record_order_fill(
-        &mut self,
-        order_id: u64,
-        tx_hash: String,
-        fill_price: f64,
-        filled_at: u64,
-    ) {
-        if let Ok(Some(mut order)) = self.state.orders.get(&order_id).await {
-            // Prevent duplicate fills
-            if !matches!(order.status, OrderStatus::Filled) {
-                order.status = OrderStatus::Filled;
-                order.tx_hash = Some(tx_hash.clone());
-                order.fill_price = Some(fill_price);
-                order.filled_at = Some(filled_at);
-
-                let _ = self.state.orders.insert(&order_id, order);
+    /// Remove a curator's review and its share of the aggregate rating.
+    /// Returns false if there was none.
+    async fn withdraw_rating(&mut self, strategy_id: u64, curator: &String) -> bool {
+        let Ok(reviews) = self.state.strategy_reviews.load_entry_mut(&strategy_id).await else {
+            return false;
+        };
+        let Ok(Some(review)) = reviews.get(curator).await else {
+            return false;
+        };
+        let _ = reviews.remove(curator);
+        if let Ok(reviewed) = self.state.curator_reviews.load_entry_mut(curator).await {
+            let _ = reviewed.remove(&strategy_id);
+        }
+        let mut ratings = self.state.strategy_ratings.get(&strategy_id).await.ok().flatten().unwrap_or_default();
+        ratings.remove(review.rating);
+        let _ = self.state.strategy_ratings.insert(&strategy_id, ratings);
+        true
+    }
 
-                let event = Event::OrderFilled {
-                    order_id,
-                    tx_hash,
-                    fill_price,
-                };
-                let stream_name = StreamName::from(bcs::to_bytes(&"order_filled").unwrap());
-                self.runtime.emit(stream_name, &event);
-            }
+    fn set_signal_policy(&mut self, policy: SignalPolicy) {
+        if !self.is_admin() || policy.max_signals_per_window == 0 || policy.rate_window_micros == 0 {
+            return;
         }
+        self.state.signal_policy.set(policy);
     }
 
-    // DEX Operations
-    async fn create_dex_order(&mut self, mut order: DEXOrder) {
-        // Generate ID
-        let id = *self.state.dex_order_counter.get() + 1;
-        order.id = id;
-        self.state.dex_order_counter.set(id);
+    // ============================================
+    // INFLUENCER REPUTATION METHODS
+    // ============================================
 
-        // Store order
-        let _ = self.state.dex_orders.insert(&id, order.clone());
+    /// Record the price a signal's token traded at one horizon after the
+    /// call. Each horizon is recorded once, and only once it has elapsed.
+    async fn record_signal_price(&mut self, signal_id: u64, horizon: OutcomeHorizon, price: f64) {
+        if !price.is_finite() || price <= 0.0 || !self.is_price_reporter().await {
+            return;
+        }
+        let Ok(Some(signal)) = self.state.signals.get(&signal_id).await else {
+            return;
+        };
+        let Ok(Some(mut outcome)) = self.state.signal_outcomes.get(&signal_id).await else {
+            return;
+        };
+        let now = self.runtime.system_time().micros();
+        if outcome.horizon_return(horizon).is_some() || now < outcome.submitted_at + horizon.micros() {
+            return;
+        }
+        let Some(directional_return) = signal.directional_return(price) else {
+            return;
+        };
+
+        outcome.set_horizon_return(horizon, directional_return);
+        let influencer = outcome.influencer.clone();
+        let _ = self.state.signal_outcomes.insert(&signal_id, outcome);
+
+        let mut stats = self.state.influencers.get(&influencer).await.ok().flatten()
+            .unwrap_or_else(|| InfluencerStats::new(influencer.clone()));
+        stats.record_return(horizon, directional_return);
+        let reputation_score = stats.reputation_score;
+        let _ = self.state.influencers.insert(&influencer, stats);
 
         // Emit event
-        let event = Event::DEXOrderCreated { order };
-        let stream_name = StreamName::from(bcs::to_bytes(&"dex_order_created").unwrap());
+        let event = Event::SignalOutcomeRecorded {
+            signal_id,
+            influencer,
+            horizon: Some(horizon),
+            target_hit: None,
+            reputation_score,
+        };
+        let stream_name = StreamName::from(bcs::to_bytes(&"signal_outcome_recorded").unwrap());
         self.runtime.emit(stream_name, &event);
     }
 
-    async fn execute_dex_order(&mut self, order_id: u64, tx_signature: String) {
-        if let Ok(Some(mut order)) = self.state.dex_orders.get(&order_id).await {
-            if !matches!(order.status, OrderStatus::Filled) {
-                order.status = OrderStatus::Filled;
-                order.tx_signature = Some(tx_signature.clone());
-                order.executed_at = Some(self.runtime.system_time().micros());
+    /// Advance every open signal on `contract` with a reported price
+    async fn report_token_price(&mut self, contract: String, price: f64) {
+        if !price.is_finite() || price <= 0.0 || !self.is_price_reporter().await {
+            return;
+        }
+        let now = self.runtime.system_time().micros();
+        let lifetime = self.state.signal_policy.get().signal_lifetime_micros;
+
+        // Take a bounded batch from the front of the queue; the ones still
+        // open go to the back, so every open signal is reached in turn
+        let Ok(open) = self.state.open_signals.load_entry_mut(&contract).await else {
+            return;
+        };
+        let due = open.read_front(MAX_PRICE_REPORT_SIGNALS).await.unwrap_or_default();
+        for _ in 0..due.len() {
+            open.delete_front();
+        }
+        if due.is_empty() {
+            let _ = self.state.open_signals.remove_entry(&contract);
+            return;
+        }
+
+        let mut still_open = Vec::with_capacity(due.len());
+        for signal_id in due {
+            let Ok(Some(signal)) = self.state.signals.get(&signal_id).await else {
+                continue;
+            };
+            let Ok(Some(mut outcome)) = self.state.signal_outcomes.get(&signal_id).await else {
+                continue;
+            };
+            // Signals resolved elsewhere, e.g. by the expiry sweep, leave the queue here
+            if outcome.status.is_resolved() {
+                continue;
+            }
+            if outcome.observe_price(&signal, price, now, lifetime) {
+                self.on_signal_lifecycle_changed(&outcome).await;
+            }
+            if !outcome.status.is_resolved() {
+                still_open.push(signal_id);
+            }
+            let _ = self.state.signal_outcomes.insert(&signal_id, outcome);
+        }
+
+        let Ok(open) = self.state.open_signals.load_entry_mut(&contract).await else {
+            return;
+        };
+        for signal_id in still_open {
+            open.push_back(signal_id);
+        }
+        if open.count() == 0 {
+            let _ = self.state.open_signals.remove_entry(&contract);
+        }
+    }
+
+    /// Resolve a signal at a take-profit or stop-loss reported directly
+    async fn record_signal_target_hit(&mut self, signal_id: u64, hit: TargetHit) {
+        if !self.is_price_reporter().await {
+            return;
+        }
+        let Ok(Some(signal)) = self.state.signals.get(&signal_id).await else {
+            return;
+        };
+        let Ok(Some(mut outcome)) = self.state.signal_outcomes.get(&signal_id).await else {
+            return;
+        };
+        let now = self.runtime.system_time().micros();
+        if !outcome.apply_target_hit(&signal, hit, now) {
+            return;
+        }
+        let _ = self.state.signal_outcomes.insert(&signal_id, outcome.clone());
+        self.on_signal_lifecycle_changed(&outcome).await;
+    }
+
+    /// Expire unresolved signals in bounded batches, resuming from the
+    /// previous sweep's position and wrapping around at the end
+    async fn sweep_expired_signals(&mut self, limit: u32) {
+        let limit = limit.min(MAX_EXPIRY_SWEEP) as u64;
+        let now = self.runtime.system_time().micros();
+        let lifetime = self.state.signal_policy.get().signal_lifetime_micros;
+
+        let counter = *self.state.signal_counter.get();
+        let mut cursor = *self.state.signal_expiry_cursor.get();
+        for _ in 0..limit.min(counter) {
+            cursor = if cursor >= counter { 1 } else { cursor + 1 };
+            let Ok(Some(signal)) = self.state.signals.get(&cursor).await else {
+                continue;
+            };
+            let Ok(Some(mut outcome)) = self.state.signal_outcomes.get(&cursor).await else {
+                continue;
+            };
+            if !outcome.expire_if_due(&signal, now, lifetime) {
+                continue;
+            }
+            // The next price report for the contract drops it from the open queue
+            let _ = self.state.signal_outcomes.insert(&cursor, outcome.clone());
+            self.on_signal_lifecycle_changed(&outcome).await;
+        }
+        self.state.signal_expiry_cursor.set(cursor);
+    }
+
+    /// Emit the lifecycle change and credit take-profit or stop-loss hits
+    /// to the influencer's reputation
+    async fn on_signal_lifecycle_changed(&mut self, outcome: &SignalOutcome) {
+        let event = Event::SignalLifecycleChanged {
+            signal_id: outcome.signal_id,
+            status: outcome.status,
+            realized_return: outcome.realized_return,
+        };
+        let stream_name = StreamName::from(bcs::to_bytes(&"signal_lifecycle_changed").unwrap());
+        self.runtime.emit(stream_name, &event);
+
+        let hit = match outcome.status {
+            SignalLifecycle::TakeProfitHit => TargetHit::TakeProfit,
+            SignalLifecycle::StopHit => TargetHit::StopLoss,
+            _ => return,
+        };
+        let mut stats = self.state.influencers.get(&outcome.influencer).await.ok().flatten()
+            .unwrap_or_else(|| InfluencerStats::new(outcome.influencer.clone()));
+        stats.record_target_hit(hit);
+        let reputation_score = stats.reputation_score;
+        let _ = self.state.influencers.insert(&outcome.influencer, stats);
+
+        let event = Event::SignalOutcomeRecorded {
+            signal_id: outcome.signal_id,
+            influencer: outcome.influencer.clone(),
+            horizon: None,
+            target_hit: Some(hit),
+            reputation_score,
+        };
+        let stream_name = StreamName::from(bcs::to_bytes(&"signal_outcome_recorded").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
+
+    /// Prices come from the admin or authorised signal submitters
+    async fn is_price_reporter(&mut self) -> bool {
+        if self.is_admin() {
+            return true;
+        }
+        match self.runtime.authenticated_signer() {
+            Some(signer) => self
+                .state
+                .signal_submitters
+                .contains_key(&signer.to_string())
+                .await
+                .unwrap_or(false),
+            None => false,
+        }
+    }
+
+    // ============================================
+    // BATCH OPERATIONS
+    // ============================================
+
+    /// Batches are all-or-nothing: every item is checked before any is
+    /// applied, and a batch with a rejected item changes nothing.
+    fn check_batch_size(&self, items: usize) -> Result<(), String> {
+        let max_batch_size = match *self.state.max_batch_size.get() {
+            0 => DEFAULT_MAX_BATCH_SIZE,
+            max_batch_size => max_batch_size,
+        };
+        if items > max_batch_size as usize {
+            return Err(format!("batch of {} items exceeds the maximum of {}", items, max_batch_size));
+        }
+        Ok(())
+    }
+
+    fn emit_batch_executed(&mut self, kind: &str, items: usize) {
+        let event = Event::BatchExecuted { kind: kind.to_string(), items };
+        let stream_name = StreamName::from(bcs::to_bytes(&"batch_executed").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
+
+    /// Per-item results for a batch that is not applied, if any item failed
+    fn reject_batch(&mut self, kind: &str, errors: Vec<Option<String>>) -> Option<Vec<BatchItem>> {
+        let items = BatchItem::rejections(errors)?;
+        let rejected = items.iter().filter(|item| matches!(item, BatchItem::Rejected(_))).count();
+        let event = Event::BatchRejected { kind: kind.to_string(), rejected };
+        let stream_name = StreamName::from(bcs::to_bytes(&"batch_rejected").unwrap());
+        self.runtime.emit(stream_name, &event);
+        Some(items)
+    }
+
+    async fn submit_signal_batch(&mut self, signals: Vec<Signal>) -> Vec<BatchItem> {
+        if let Err(reason) = self.check_batch_size(signals.len()) {
+            return vec![BatchItem::Rejected(reason); signals.len()];
+        }
+        let mut errors: Vec<Option<SignalError>> = vec![None; signals.len()];
+        match self.signal_submitter().await {
+            Err(error) => errors.fill(Some(error)),
+            Ok(submitter) => {
+                let duplicate_window = self.state.signal_policy.get().duplicate_window_micros;
+                let mut tweets: BTreeMap<&str, usize> = BTreeMap::new();
+                let mut calls: BTreeMap<String, usize> = BTreeMap::new();
+                for (index, signal) in signals.iter().enumerate() {
+                    if let Err(error) = self.check_signal_content(signal).await {
+                        errors[index] = Some(error);
+                        continue;
+                    }
+                    if !signal.tweet_url.is_empty() {
+                        if let Some(&earlier) = tweets.get(signal.tweet_url.as_str()) {
+                            errors[index] = Some(SignalError::DuplicateInBatch(earlier));
+                            continue;
+                        }
+                        tweets.insert(&signal.tweet_url, index);
+                    }
+                    let call_key = format!("{}:{}", signal.influencer, signal.contract);
+                    if let Some(&earlier) = calls.get(&call_key).filter(|_| duplicate_window > 0) {
+                        errors[index] = Some(SignalError::DuplicateInBatch(earlier));
+                        continue;
+                    }
+                    calls.insert(call_key, index);
+                }
+                // The whole batch must fit in the rate limit
+                if errors.iter().all(Option::is_none) {
+                    if let Err(error) = self.record_submissions(&submitter, signals.len() as u32).await {
+                        errors.fill(Some(error));
+                    }
+                }
+            }
+        }
+
+        for (signal, error) in signals.iter().zip(&errors) {
+            if let Some(reason) = error {
+                self.emit_signal_rejected(signal, reason.clone());
+            }
+        }
+        let errors = errors.into_iter().map(|error| error.map(|error| error.to_string())).collect();
+        if let Some(items) = self.reject_batch("signal", errors) {
+            return items;
+        }
+
+        let mut items = Vec::with_capacity(signals.len());
+        for signal in signals {
+            items.push(BatchItem::Applied(self.store_signal(signal).await));
+        }
+        self.emit_batch_executed("signal", items.len());
+        items
+    }
+
+    async fn create_order_batch(&mut self, mut orders: Vec<Order>) -> Vec<BatchItem> {
+        if let Err(reason) = self.check_batch_size(orders.len()) {
+            return vec![BatchItem::Rejected(reason); orders.len()];
+        }
+        let errors = orders.iter_mut().map(|order| self.prepare_order(order).err()).collect();
+        if let Some(items) = self.reject_batch("order", errors) {
+            return items;
+        }
+
+        let items: Vec<BatchItem> = orders
+            .into_iter()
+            .map(|order| BatchItem::Applied(self.store_order(order)))
+            .collect();
+        self.emit_batch_executed("order", items.len());
+        items
+    }
+
+    async fn record_order_fill_batch(&mut self, fills: Vec<OrderFill>) -> Vec<BatchItem> {
+        if let Err(reason) = self.check_batch_size(fills.len()) {
+            return vec![BatchItem::Rejected(reason); fills.len()];
+        }
+        let mut errors = Vec::with_capacity(fills.len());
+        let mut filled: BTreeMap<u64, usize> = BTreeMap::new();
+        let mut brackets: BTreeMap<u64, usize> = BTreeMap::new();
+        for (index, fill) in fills.iter().enumerate() {
+            let error = match self.check_order_fill(fill.order_id, &fill.tx_hash).await {
+                Err(reason) => Some(reason),
+                Ok(order) => {
+                    // Each order fills once, and only one leg of a bracket fills
+                    let bracket = order.and_then(|order| order.parent_order_id);
+                    if let Some(&earlier) = filled.get(&fill.order_id) {
+                        Some(format!("order already filled by batch item {}", earlier))
+                    } else if let Some(&earlier) = bracket.and_then(|entry| brackets.get(&entry)) {
+                        Some(format!("bracket sibling filled by batch item {}", earlier))
+                    } else {
+                        filled.insert(fill.order_id, index);
+                        if let Some(entry_order_id) = bracket {
+                            brackets.insert(entry_order_id, index);
+                        }
+                        None
+                    }
+                }
+            };
+            errors.push(error);
+        }
+        if let Some(items) = self.reject_batch("fill", errors) {
+            return items;
+        }
+
+        let mut items = Vec::with_capacity(fills.len());
+        for fill in fills {
+            let recorded = self
+                .record_order_fill(fill.order_id, fill.tx_hash, fill.fill_price, fill.filled_at)
+                .await;
+            // Every fill was checked above, so none can be rejected here
+            assert!(recorded, "checked fill for order {} was rejected", fill.order_id);
+            items.push(BatchItem::Applied(fill.order_id));
+        }
+        self.emit_batch_executed("fill", items.len());
+        items
+    }
+
+    async fn set_max_batch_size(&mut self, max_batch_size: u32) {
+        if !self.is_admin() || max_batch_size == 0 {
+            return;
+        }
+        self.state.max_batch_size.set(max_batch_size);
+    }
+
+    fn set_immediate_order_window(&mut self, window_micros: u64) {
+        if !self.is_admin() || window_micros == 0 {
+            return;
+        }
+        self.state.immediate_order_window.set(window_micros);
+    }
+
+    /// How long IOC and FOK orders stay live
+    fn immediate_order_window(&self) -> u64 {
+        match *self.state.immediate_order_window.get() {
+            0 => DEFAULT_IMMEDIATE_ORDER_WINDOW_MICROS,
+            window => window,
+        }
+    }
+
+    /// Whether the authenticated signer is the application admin
+    fn is_admin(&mut self) -> bool {
+        let signer = self.runtime.authenticated_signer().map(|owner| owner.to_string());
+        signer.is_some() && *self.state.admin.get() == signer
+    }
+
+    /// Dedupe key for a client-supplied request ID, scoped to the signer and
+    /// the kind of object being created
+    fn client_request_key(&mut self, kind: &str, client_request_id: &Option<String>) -> Option<String> {
+        let signer = self.runtime.authenticated_signer()
+            .map(|owner| owner.to_string())
+            .unwrap_or_else(|| "unknown".to_string());
+        client_request_id
+            .as_ref()
+            .map(|request_id| format!("{}:{}:{}", signer, kind, request_id))
+    }
+
+    /// ID assigned the first time this request was executed, if any
+    async fn replayed_request(&self, key: &Option<String>) -> Option<u64> {
+        let key = key.as_ref()?;
+        self.state.client_requests.get(key).await.ok().flatten()
+    }
+
+    /// Remember the ID assigned to a request; rejected requests (ID 0) may be retried
+    fn remember_request(&mut self, key: Option<String>, id: u64) {
+        if let Some(key) = key {
+            if id != 0 {
+                let _ = self.state.client_requests.insert(&key, id);
+            }
+        }
+    }
+
+    /// Whether a fill with this transaction was already recorded for the order
+    async fn is_fill_replay(&self, order_kind: &str, order_id: u64, tx: &str) -> bool {
+        let key = format!("{}:{}:{}", order_kind, order_id, tx);
+        self.state.processed_fills.contains_key(&key).await.unwrap_or(false)
+    }
+
+    fn remember_fill(&mut self, order_kind: &str, order_id: u64, tx: &str) {
+        let key = format!("{}:{}:{}", order_kind, order_id, tx);
+        let _ = self.state.processed_fills.insert(&key, ());
+    }
+
+    async fn submit_signal(&mut self, signal: Signal) -> u64 {
+        // Validate signal
+        if let Err(reason) = self.check_signal(&signal).await {
+            self.emit_signal_rejected(&signal, reason);
+            return 0;
+        }
+        self.store_signal(signal).await
+    }
+
+    fn emit_signal_rejected(&mut self, signal: &Signal, reason: SignalError) {
+        let event = Event::SignalRejected {
+            influencer: signal.influencer.clone(),
+            tweet_url: signal.tweet_url.clone(),
+            reason,
+        };
+        let stream_name = StreamName::from(bcs::to_bytes(&"signal_rejected").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
+
+    /// Store a checked signal and open its outcome tracking
+    async fn store_signal(&mut self, mut signal: Signal) -> u64 {
+        // Generate ID
+        let id = *self.state.signal_counter.get() + 1;
+        signal.id = id;
+        self.state.signal_counter.set(id);
+
+        // Store signal
+        let _ = self.state.signals.insert(&id, signal.clone());
+
+        // Register the call with its influencer
+        let now = self.runtime.system_time().micros();
+        let mut stats = self.state.influencers.get(&signal.influencer).await.ok().flatten()
+            .unwrap_or_else(|| InfluencerStats::new(signal.influencer.clone()));
+        stats.total_signals += 1;
+        stats.last_signal_at = now;
+        let _ = self.state.influencers.insert(&signal.influencer, stats);
+        let outcome = SignalOutcome::new(id, signal.influencer.clone(), now);
+        let _ = self.state.signal_outcomes.insert(&id, outcome);
+        if let Ok(open) = self.state.open_signals.load_entry_mut(&signal.contract).await {
+            open.push_back(id);
+        }
+
+        // Index for dedupe
+        if !signal.tweet_url.is_empty() {
+            let _ = self.state.signal_by_tweet.insert(&signal.tweet_url, id);
+        }
+        let call_key = format!("{}:{}", signal.influencer, signal.contract);
+        let _ = self.state.last_call.insert(&call_key, (id, now));
+
+        // Emit event
+        let event = Event::SignalReceived { signal };
+        let stream_name = StreamName::from(bcs::to_bytes(&"signal_received").unwrap());
+        self.runtime.emit(stream_name, &event);
+        id
+    }
+
+    async fn create_strategy(&mut self, mut strategy: Strategy) -> u64 {
+        if !strategy.fees.is_valid() || !strategy.rules_are_valid() {
+            return 0;
+        }
+        // Curation comes from curator ratings, not the creator
+        if matches!(strategy.source, StrategySource::Curated { .. }) {
+            return 0;
+        }
+        // Only ForkStrategy records provenance
+        strategy.forked_from = None;
+        self.insert_new_strategy(strategy, Some("created".to_string()))
+    }
+
+    /// Assign an id and v1 to a validated strategy and store it
+    fn insert_new_strategy(&mut self, mut strategy: Strategy, change_reason: Option<String>) -> u64 {
+        // Generate ID; versioning starts at v1
+        let id = *self.state.strategy_counter.get() + 1;
+        let now = self.runtime.system_time().micros();
+        strategy.id = id;
+        strategy.created_at = now;
+        strategy.version = 1;
+        strategy.updated_at = None;
+        strategy.follower_count = 0;
+        strategy.risk_version = 1;
+        strategy.fork_count = 0;
+        self.state.strategy_counter.set(id);
+
+        // Store strategy and its first version
+        let owner = strategy.owner.clone();
+        self.record_strategy_version(strategy.clone(), now, change_reason);
+        let _ = self.state.strategies.insert(&id, strategy);
+
+        // Emit event
+        let event = Event::StrategyCreated {
+            strategy_id: id,
+            owner,
+        };
+        let stream_name = StreamName::from(bcs::to_bytes(&"strategy_created").unwrap());
+        self.runtime.emit(stream_name, &event);
+        id
+    }
+
+    /// Copy a strategy into the signer's ownership. Returns the fork's id,
+    /// or 0 if rejected.
+    async fn fork_strategy(&mut self, strategy_id: u64, overrides: StrategyOverrides) -> u64 {
+        let Some(owner) = self.runtime.authenticated_signer().map(|owner| owner.to_string()) else {
+            return 0;
+        };
+        // Only strategies whose rules anyone may read can be copied
+        let Ok(Some(mut parent)) = self.state.strategies.get(&strategy_id).await else {
+            return 0;
+        };
+        if parent.visibility != StrategyVisibility::Public || parent.strategy_type == StrategyType::Hidden {
+            return 0;
+        }
+        let fork = parent.fork(owner.clone(), overrides);
+        if !fork.fees.is_valid() || !fork.rules_are_valid() {
+            return 0;
+        }
+        let origin = ForkOrigin { strategy_id, version: parent.version };
+        let change_reason = Some(format!("forked from strategy {} v{}", strategy_id, parent.version));
+        let id = self.insert_new_strategy(fork, change_reason);
+
+        parent.fork_count += 1;
+        let _ = self.state.strategies.insert(&strategy_id, parent);
+        if let Ok(forks) = self.state.strategy_forks.load_entry_mut(&strategy_id).await {
+            let _ = forks.insert(&id);
+        }
+
+        // Emit event
+        let event = Event::StrategyForked { strategy_id: id, owner, origin };
+        let stream_name = StreamName::from(bcs::to_bytes(&"strategy_forked").unwrap());
+        self.runtime.emit(stream_name, &event);
+        id
+    }
+
+    async fn activate_strategy(&mut self, strategy_id: u64) {
+        if !self.is_strategy_owner(strategy_id).await {
+            return;
+        }
+        if let Ok(Some(mut strategy)) = self.state.strategies.get(&strategy_id).await {
+            strategy.active = true;
+            let _ = self.state.strategies.insert(&strategy_id, strategy);
+
+            let event = Event::StrategyActivated { strategy_id };
+            let stream_name = StreamName::from(bcs::to_bytes(&"strategy_activated").unwrap());
+            self.runtime.emit(stream_name, &event);
+        }
+    }
+
+    async fn deactivate_strategy(&mut self, strategy_id: u64) {
+        if !self.is_strategy_owner(strategy_id).await {
+            return;
+        }
+        if let Ok(Some(mut strategy)) = self.state.strategies.get(&strategy_id).await {
+            strategy.active = false;
+            let _ = self.state.strategies.insert(&strategy_id, strategy);
+
+            let event = Event::StrategyDeactivated { strategy_id };
+            let stream_name = StreamName::from(bcs::to_bytes(&"strategy_deactivated").unwrap());
+            self.runtime.emit(stream_name, &event);
+        }
+    }
+
+    async fn create_order(&mut self, mut order: Order) -> u64 {
+        if let Err(reason) = self.prepare_order(&mut order) {
+            let event = Event::OrderRejected { strategy_id: order.strategy_id, reason };
+            let stream_name = StreamName::from(bcs::to_bytes(&"order_rejected").unwrap());
+            self.runtime.emit(stream_name, &event);
+            return 0;
+        }
+        self.store_order(order)
+    }
+
+    /// Set a new order's contract-computed fields and check it may be created
+    fn prepare_order(&mut self, order: &mut Order) -> Result<(), String> {
+        // Bracket children are only created by the contract
+        order.parent_order_id = None;
+        let now = self.runtime.system_time().micros();
+        order.expires_at = order.time_in_force.expires_at(now, self.immediate_order_window());
+        if is_expired(order.expires_at, &order.status, now) {
+            return Err("already expired".to_string());
+        }
+        if order.bracket.as_ref().is_some_and(|bracket| !bracket.is_valid(order.is_short())) {
+            return Err("invalid bracket".to_string());
+        }
+        Ok(())
+    }
+
+    fn store_order(&mut self, mut order: Order) -> u64 {
+        // Generate ID
+        let id = *self.state.order_counter.get() + 1;
+        order.id = id;
+        self.state.order_counter.set(id);
+
+        // Store order
+        let _ = self.state.orders.insert(&id, order.clone());
+
+        // Emit event
+        let event = Event::OrderCreated { order };
+        let stream_name = StreamName::from(bcs::to_bytes(&"order_created").unwrap());
+        self.runtime.emit(stream_name, &event);
+        id
+    }
+
+    /// Why a reported fill would be rejected, checked without recording
+    /// anything. Returns the order, or `None` for an already recorded fill.
+    async fn check_order_fill(&mut self, order_id: u64, tx_hash: &str) -> Result<Option<Order>, String> {
+        if self.is_fill_replay("order", order_id, tx_hash).await {
+            return Ok(None);
+        }
+        let Ok(Some(order)) = self.state.orders.get(&order_id).await else {
+            return Err(format!("unknown order {}", order_id));
+        };
+        let now = self.runtime.system_time().micros();
+        if is_expired(order.expires_at, &order.status, now) {
+            return Err("order expired".to_string());
+        }
+        if let Some(replication) = self.replication_of(order_id).await {
+            if now < replication.not_before {
+                return Err("copy is delayed".to_string());
+            }
+        }
+        if !order.can_fill() {
+            return Err(format!("order is {:?}", order.status));
+        }
+        Ok(Some(order))
+    }
+
+    async fn record_order_fill(
+        &mut self,
+        order_id: u64,
+        tx_hash: String,
+        fill_price: f64,
+        filled_at: u64,
+    ) -> bool {
+        if self.is_fill_replay("order", order_id, &tx_hash).await {
+            return true;
+        }
+        if let Ok(Some(mut order)) = self.state.orders.get(&order_id).await {
+            if self.expire_order_if_due(&mut order).await {
+                return false;
+            }
+            // Delayed copies may not execute before their follower's delay
+            if let Some(replication) = self.replication_of(order_id).await {
+                if self.runtime.system_time().micros() < replication.not_before {
+                    return false;
+                }
+            }
+            // Prevent duplicate fills
+            if order.can_fill() {
+                order.apply_fill(tx_hash.clone(), fill_price, filled_at);
+
+                let _ = self.state.orders.insert(&order_id, order.clone());
+                self.remember_fill("order", order_id, &tx_hash);
+
+                let event = Event::OrderFilled {
+                    order_id,
+                    tx_hash,
+                    fill_price,
+                };
+                let stream_name = StreamName::from(bcs::to_bytes(&"order_filled").unwrap());
+                self.runtime.emit(stream_name, &event);
+                self.settle_replication(order_id, ReplicationStatus::Executed).await;
+
+                // Entry fills open their bracket; bracket fills cancel their sibling
+                if let Some(entry_order_id) = order.parent_order_id {
+                    self.on_bracket_leg_filled(entry_order_id, order_id).await;
+                } else if order.bracket.is_some() {
+                    self.open_bracket(order).await;
+                }
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Create the linked stop-loss and take-profit orders for a filled entry
+    async fn open_bracket(&mut self, entry: Order) {
+        let Some(spec) = entry.bracket.clone() else {
+            return;
+        };
+        if self.state.brackets.contains_key(&entry.id).await.unwrap_or(true) {
+            return;
+        }
+
+        let now = self.runtime.system_time().micros();
+        let mut child_ids = Vec::new();
+        for (trigger_price, leg) in [(spec.stop_loss, BracketLeg::StopLoss), (spec.take_profit, BracketLeg::TakeProfit)] {
+            let id = *self.state.order_counter.get() + 1;
+            self.state.order_counter.set(id);
+            let child = entry.bracket_child(id, leg, trigger_price, now);
+            let _ = self.state.orders.insert(&id, child.clone());
+            child_ids.push(id);
+
+            let event = Event::OrderCreated { order: child };
+            let stream_name = StreamName::from(bcs::to_bytes(&"order_created").unwrap());
+            self.runtime.emit(stream_name, &event);
+        }
+
+        let bracket = Bracket {
+            entry_order_id: entry.id,
+            stop_loss_order_id: child_ids[0],
+            take_profit_order_id: child_ids[1],
+            status: BracketStatus::Active,
+            created_at: now,
+            closed_at: None,
+        };
+        let _ = self.state.brackets.insert(&entry.id, bracket);
+
+        // Emit event
+        let event = Event::BracketCreated {
+            entry_order_id: entry.id,
+            stop_loss_order_id: child_ids[0],
+            take_profit_order_id: child_ids[1],
+        };
+        let stream_name = StreamName::from(bcs::to_bytes(&"bracket_created").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
+
+    /// One-cancels-other: a filled bracket leg cancels its sibling
+    async fn on_bracket_leg_filled(&mut self, entry_order_id: u64, filled_order_id: u64) {
+        if let Ok(Some(mut bracket)) = self.state.brackets.get(&entry_order_id).await {
+            if bracket.status != BracketStatus::Active {
+                return;
+            }
+            let Some((leg, sibling_id)) = bracket.leg_of(filled_order_id) else {
+                return;
+            };
+
+            self.cancel_order(sibling_id, "bracket sibling filled").await;
+
+            bracket.status = BracketStatus::Triggered { leg: leg.clone() };
+            bracket.closed_at = Some(self.runtime.system_time().micros());
+            let _ = self.state.brackets.insert(&entry_order_id, bracket);
+
+            // Emit event
+            let event = Event::BracketTriggered {
+                entry_order_id,
+                leg,
+                filled_order_id,
+                cancelled_order_id: sibling_id,
+            };
+            let stream_name = StreamName::from(bcs::to_bytes(&"bracket_triggered").unwrap());
+            self.runtime.emit(stream_name, &event);
+
+            self.record_closed_trade(entry_order_id, filled_order_id).await;
+        }
+    }
+
+    /// Fold a realized round trip into the risk statistics and equity
+    /// curves of its strategy and of the strategy owner
+    async fn record_closed_trade(&mut self, entry_order_id: u64, exit_order_id: u64) {
+        let Ok(Some(entry)) = self.state.orders.get(&entry_order_id).await else {
+            return;
+        };
+        let Ok(Some(exit)) = self.state.orders.get(&exit_order_id).await else {
+            return;
+        };
+        let Some(trade) = ClosedTrade::from_orders(&entry, &exit) else {
+            return;
+        };
+
+        // A follower's copy counts toward the follower's profile and fees,
+        // not the strategy's own record
+        if let Some(replication) = self.replication_of(entry_order_id).await {
+            let follower_id = replication.follower_id;
+            if let Some(mut follower) = self.load_follow(trade.strategy_id, &follower_id).await {
+                follower.closed_trades += 1;
+                follower.realized_pnl += trade.pnl();
+                self.store_follow(follower).await;
+            }
+            self.record_profile_trade(&follower_id, &trade).await;
+            self.accrue_performance_fee(trade.strategy_id, &follower_id, trade.pnl()).await;
+            self.emit_trade_closed(&trade);
+            return;
+        }
+
+        let mut stats = self.state.strategy_performance.get(&trade.strategy_id).await.ok().flatten().unwrap_or_default();
+        let point = stats.record_trade(&trade);
+        let _ = self.state.strategy_performance.insert(&trade.strategy_id, stats);
+        let mut curve = self.state.strategy_equity.get(&trade.strategy_id).await.ok().flatten().unwrap_or_default();
+        push_equity_point(&mut curve, point);
+        let _ = self.state.strategy_equity.insert(&trade.strategy_id, curve);
+
+        if let Ok(Some(strategy)) = self.state.strategies.get(&trade.strategy_id).await {
+            self.record_profile_trade(&strategy.owner, &trade).await;
+        }
+        self.emit_trade_closed(&trade);
+    }
+
+    async fn record_profile_trade(&mut self, owner: &str, trade: &ClosedTrade) {
+        let profile = match self.profile_id_of(&owner.to_string()).await {
+            Some(profile_id) => self.state.microchain_profiles.get(&profile_id).await.ok().flatten(),
+            None => None,
+        };
+        // A profile with its own chain keeps its stats there, and reports
+        // them back to this registry
+        let chain_id = self.runtime.chain_id();
+        if let Some(hosted) = profile.as_ref().and_then(|profile| profile.chain_id) {
+            if hosted != chain_id {
+                let message = Message::RecordProfileTrade { owner: owner.to_string(), trade: trade.clone() };
+                self.runtime.prepare_message(message).with_tracking().send_to(hosted);
+                return;
+            }
+        }
+
+        let mut stats = self.state.profile_performance.get(owner).await.ok().flatten().unwrap_or_default();
+        let point = stats.record_trade(trade);
+        let _ = self.state.profile_performance.insert(owner, stats.clone());
+        let mut curve = self.state.profile_equity.get(owner).await.ok().flatten().unwrap_or_default();
+        push_equity_point(&mut curve, point.clone());
+        let _ = self.state.profile_equity.insert(owner, curve);
+
+        // Keep the profile's headline counters in step
+        if let Some(mut profile) = profile {
+            self.report_to_registry(Message::ProfileTradeRecorded { owner: owner.to_string(), stats, point });
+            profile.total_trades += 1;
+            if trade.pnl() > 0.0 {
+                profile.winning_trades += 1;
+            }
+            profile.total_volume += trade.notional().round() as u64;
+            profile.total_pnl += trade.pnl().round() as i64;
+            let _ = self.state.microchain_profiles.insert(&profile.id, profile.clone());
+            self.report_to_registry(Message::ProfileUpdated { profile });
+        }
+    }
+
+    fn emit_trade_closed(&mut self, trade: &ClosedTrade) {
+        let event = Event::TradeClosed {
+            strategy_id: trade.strategy_id,
+            entry_order_id: trade.entry_order_id,
+            exit_order_id: trade.exit_order_id,
+            pnl: trade.pnl(),
+            return_pct: trade.return_pct(),
+        };
+        let stream_name = StreamName::from(bcs::to_bytes(&"trade_closed").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
+
+    async fn close_bracket_position(&mut self, entry_order_id: u64) {
+        let Ok(Some(entry)) = self.state.orders.get(&entry_order_id).await else {
+            return;
+        };
+        if !self.is_strategy_owner(entry.strategy_id).await {
+            return;
+        }
+        if let Ok(Some(mut bracket)) = self.state.brackets.get(&entry_order_id).await {
+            if bracket.status != BracketStatus::Active {
+                return;
+            }
+
+            self.cancel_order(bracket.stop_loss_order_id, "position closed").await;
+            self.cancel_order(bracket.take_profit_order_id, "position closed").await;
+
+            bracket.status = BracketStatus::Closed;
+            bracket.closed_at = Some(self.runtime.system_time().micros());
+            let _ = self.state.brackets.insert(&entry_order_id, bracket);
+
+            // Emit event
+            let event = Event::BracketClosed { entry_order_id };
+            let stream_name = StreamName::from(bcs::to_bytes(&"bracket_closed").unwrap());
+            self.runtime.emit(stream_name, &event);
+        }
+    }
+
+    /// Cancel an order that has not filled yet
+    async fn cancel_order(&mut self, order_id: u64, reason: &str) {
+        if let Ok(Some(mut order)) = self.state.orders.get(&order_id).await {
+            if self.expire_order_if_due(&mut order).await {
+                return;
+            }
+            if !matches!(order.status, OrderStatus::Pending | OrderStatus::Submitted) {
+                return;
+            }
+            order.status = OrderStatus::Cancelled;
+            let _ = self.state.orders.insert(&order_id, order);
+
+            let event = Event::OrderCancelled { order_id, reason: reason.to_string() };
+            let stream_name = StreamName::from(bcs::to_bytes(&"order_cancelled").unwrap());
+            self.runtime.emit(stream_name, &event);
+            let status = ReplicationStatus::Failed { reason: format!("cancelled: {}", reason) };
+            self.settle_replication(order_id, status).await;
+        }
+    }
+
+    /// Record that an open order could not be executed
+    async fn record_order_failure(&mut self, order_id: u64, reason: String) {
+        let Ok(Some(mut order)) = self.state.orders.get(&order_id).await else {
+            return;
+        };
+        if self.expire_order_if_due(&mut order).await || !order.status.is_open() {
+            return;
+        }
+        order.status = OrderStatus::Failed;
+        let _ = self.state.orders.insert(&order_id, order);
+
+        let event = Event::OrderFailed { order_id, reason: reason.clone() };
+        let stream_name = StreamName::from(bcs::to_bytes(&"order_failed").unwrap());
+        self.runtime.emit(stream_name, &event);
+        self.settle_replication(order_id, ReplicationStatus::Failed { reason }).await;
+    }
+
+    // DEX Operations
+    async fn create_dex_order(&mut self, mut order: DEXOrder) -> u64 {
+        if !self.plan_order_slices(&mut order) {
+            return 0;
+        }
+
+        // Generate ID
+        let id = *self.state.dex_order_counter.get() + 1;
+        order.id = id;
+        self.state.dex_order_counter.set(id);
+
+        // Store order
+        let _ = self.state.dex_orders.insert(&id, order.clone());
+
+        // Emit event
+        let event = Event::DEXOrderCreated { order };
+        let stream_name = StreamName::from(bcs::to_bytes(&"dex_order_created").unwrap());
+        self.runtime.emit(stream_name, &event);
+        id
+    }
+
+    async fn execute_dex_order(&mut self, order_id: u64, tx_signature: String) {
+        if self.is_fill_replay("dex_order", order_id, &tx_signature).await {
+            return;
+        }
+        if let Ok(Some(mut order)) = self.state.dex_orders.get(&order_id).await {
+            if self.expire_dex_order_if_due(&mut order).await {
+                return;
+            }
+            // Split-route and sliced orders report fills via their own operations
+            if order.is_split() || order.is_sliced() {
+                return;
+            }
+            if order.status.is_open() {
+                order.status = OrderStatus::Filled;
+                order.tx_signature = Some(tx_signature.clone());
+                order.executed_at = Some(self.runtime.system_time().micros());
 
                 let output_amount = order.output_amount;
                 let _ = self.state.dex_orders.insert(&order_id, order);
+                self.remember_fill("dex_order", order_id, &tx_signature);
 
                 let event = Event::DEXOrderExecuted {
                     order_id,
@@ -316,16 +1566,29 @@ impl LineraTradeContract {
     }
 
     // Social Trading Operations
+    /// Follow an active strategy owned by someone else. Re-following an
+    /// existing follow is rejected; use `UpdateFollow` instead.
     async fn follow_strategy(
         &mut self,
         strategy_id: u64,
         allocation_percentage: f64,
         max_position_size: f64,
         auto_follow: bool,
+        policy: ReplicationPolicy,
     ) {
-        let follower_id = self.runtime.authenticated_signer()
-            .map(|owner| owner.to_string())
-            .unwrap_or_else(|| "unknown".to_string());
+        let Some(follower_id) = self.runtime.authenticated_signer().map(|owner| owner.to_string()) else {
+            return;
+        };
+        if !is_valid_follow_sizing(allocation_percentage, max_position_size) {
+            return;
+        }
+        let consented_version = match self.state.strategies.get(&strategy_id).await {
+            Ok(Some(strategy)) if strategy.active && strategy.owner != follower_id => strategy.version,
+            _ => return,
+        };
+        if self.load_follow(strategy_id, &follower_id).await.is_some() {
+            return;
+        }
 
         let follower = StrategyFollower {
             follower_id: follower_id.clone(),
@@ -334,11 +1597,23 @@ impl LineraTradeContract {
             max_position_size,
             auto_follow,
             followed_at: self.runtime.system_time().micros(),
+            policy,
+            paused: false,
+            updated_at: None,
+            copied_trades: 0,
+            skipped_copies: 0,
+            closed_trades: 0,
+            realized_pnl: 0.0,
+            consented_version,
         };
 
         // Store follower
-        let key = format!("{}:{}", strategy_id, follower_id);
-        let _ = self.state.strategy_followers.insert(&key.as_bytes().to_vec(), follower);
+        self.store_follow(follower).await;
+        if let Ok(following) = self.state.following.load_entry_mut(&follower_id).await {
+            let _ = following.insert(&strategy_id);
+        }
+        self.adjust_follower_count(strategy_id, 1).await;
+        self.open_fee_account(strategy_id, &follower_id).await;
 
         // Emit event
         let event = Event::StrategyFollowed {
@@ -350,12 +1625,20 @@ impl LineraTradeContract {
     }
 
     async fn unfollow_strategy(&mut self, strategy_id: u64) {
-        let follower_id = self.runtime.authenticated_signer()
-            .map(|owner| owner.to_string())
-            .unwrap_or_else(|| "unknown".to_string());
-
-        let key = format!("{}:{}", strategy_id, follower_id);
-        let _ = self.state.strategy_followers.remove(&key.as_bytes().to_vec());
+        let Some(follower_id) = self.runtime.authenticated_signer().map(|owner| owner.to_string()) else {
+            return;
+        };
+        if self.load_follow(strategy_id, &follower_id).await.is_none() {
+            return;
+        }
+        if let Ok(followers) = self.state.strategy_followers.load_entry_mut(&strategy_id).await {
+            let _ = followers.remove(&follower_id);
+        }
+        if let Ok(following) = self.state.following.load_entry_mut(&follower_id).await {
+            let _ = following.remove(&strategy_id);
+        }
+        self.adjust_follower_count(strategy_id, -1).await;
+        self.close_fee_account(strategy_id, &follower_id).await;
 
         // Emit event
         let event = Event::StrategyUnfollowed {
@@ -366,42 +1649,377 @@ impl LineraTradeContract {
         self.runtime.emit(stream_name, &event);
     }
 
+    async fn load_follow(&self, strategy_id: u64, follower_id: &str) -> Option<StrategyFollower> {
+        let followers = self.state.strategy_followers.try_load_entry(&strategy_id).await.ok().flatten()?;
+        followers.get(follower_id).await.ok().flatten()
+    }
+
+    async fn store_follow(&mut self, follower: StrategyFollower) {
+        if let Ok(followers) = self.state.strategy_followers.load_entry_mut(&follower.strategy_id).await {
+            let follower_id = follower.follower_id.clone();
+            let _ = followers.insert(&follower_id, follower);
+        }
+    }
+
+    /// The signer's follow of a strategy
+    async fn own_follow(&mut self, strategy_id: u64) -> Option<StrategyFollower> {
+        let follower_id = self.runtime.authenticated_signer()?.to_string();
+        self.load_follow(strategy_id, &follower_id).await
+    }
+
+    async fn adjust_follower_count(&mut self, strategy_id: u64, delta: i64) {
+        if let Ok(Some(mut strategy)) = self.state.strategies.get(&strategy_id).await {
+            strategy.follower_count = strategy.follower_count.saturating_add_signed(delta);
+            let _ = self.state.strategies.insert(&strategy_id, strategy);
+        }
+    }
+
+    async fn update_follow(
+        &mut self,
+        strategy_id: u64,
+        allocation_percentage: f64,
+        max_position_size: f64,
+        auto_follow: bool,
+        policy: ReplicationPolicy,
+    ) {
+        if !is_valid_follow_sizing(allocation_percentage, max_position_size) {
+            return;
+        }
+        let Some(mut follower) = self.own_follow(strategy_id).await else {
+            return;
+        };
+        follower.allocation_percentage = allocation_percentage;
+        follower.max_position_size = max_position_size;
+        follower.auto_follow = auto_follow;
+        follower.policy = policy;
+        follower.updated_at = Some(self.runtime.system_time().micros());
+        let follower_id = follower.follower_id.clone();
+        self.store_follow(follower).await;
+
+        // Emit event
+        let event = Event::FollowUpdated { strategy_id, follower_id };
+        let stream_name = StreamName::from(bcs::to_bytes(&"follow_updated").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
+
+    async fn set_follow_paused(&mut self, strategy_id: u64, paused: bool) {
+        let Some(mut follower) = self.own_follow(strategy_id).await else {
+            return;
+        };
+        if follower.paused == paused {
+            return;
+        }
+        follower.paused = paused;
+        follower.updated_at = Some(self.runtime.system_time().micros());
+        let follower_id = follower.follower_id.clone();
+        self.store_follow(follower).await;
+
+        // Emit event
+        let event = Event::FollowPaused { strategy_id, follower_id, paused };
+        let stream_name = StreamName::from(bcs::to_bytes(&"follow_paused").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
+
+    async fn consent_strategy_version(&mut self, strategy_id: u64) {
+        let Some(mut follower) = self.own_follow(strategy_id).await else {
+            return;
+        };
+        let Ok(Some(strategy)) = self.state.strategies.get(&strategy_id).await else {
+            return;
+        };
+        follower.consented_version = strategy.version;
+        follower.updated_at = Some(self.runtime.system_time().micros());
+        let follower_id = follower.follower_id.clone();
+        self.store_follow(follower).await;
+
+        // Emit event
+        let event = Event::FollowConsented { strategy_id, follower_id, version: strategy.version };
+        let stream_name = StreamName::from(bcs::to_bytes(&"follow_consented").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
+
+    /// Copy a leader's order for a follower under the follower's
+    /// replication policy. Returns the replication id; blocked copies are
+    /// recorded as skipped. Returns 0 for a scale factor that is not
+    /// positive, or when the follower already has a copy of the order.
     async fn replicate_trade(
         &mut self,
         original_order_id: u64,
         follower_id: String,
         scale_factor: f64,
-    ) {
+    ) -> u64 {
+        if !scale_factor.is_finite() || scale_factor <= 0.0 {
+            return 0;
+        }
         // Get original order
-        if let Ok(Some(original_order)) = self.state.orders.get(&original_order_id).await {
-            // Create replicated order
-            let follower_order_id = *self.state.order_counter.get() + 1;
-            self.state.order_counter.set(follower_order_id);
+        let Ok(Some(original_order)) = self.state.orders.get(&original_order_id).await else {
+            return 0;
+        };
+        // One copy of a leader order per follower
+        if self.is_replicated_for(original_order_id, &follower_id).await {
+            return 0;
+        }
+        let strategy_id = original_order.strategy_id;
+        let now = self.runtime.system_time().micros();
+
+        let mut follower = self.load_follow(strategy_id, &follower_id).await;
+        let strategy = self.state.strategies.get(&strategy_id).await.ok().flatten();
+        let (copy, delay_micros) = match (&follower, &strategy) {
+            (Some(follower), _) if follower.paused => (Err("follow paused".to_string()), 0),
+            (Some(follower), Some(strategy)) => (
+                follower
+                    .policy
+                    .check_version(strategy, follower.consented_version)
+                    .and_then(|()| follower.policy.copy_order(&original_order, strategy.slippage_bps)),
+                follower.policy.delay_micros,
+            ),
+            (Some(_), None) => (Err("strategy not found".to_string()), 0),
+            (None, _) => (Err("not following strategy".to_string()), 0),
+        };
+        if let Some(follower) = follower.as_mut() {
+            if copy.is_ok() {
+                follower.copied_trades += 1;
+            } else {
+                follower.skipped_copies += 1;
+            }
+            self.store_follow(follower.clone()).await;
+        }
 
-            let mut replicated_order = original_order.clone();
-            replicated_order.id = follower_order_id;
-            replicated_order.quantity = original_order.quantity * scale_factor;
+        let id = *self.state.replication_counter.get() + 1;
+        self.state.replication_counter.set(id);
+        let mut replication = TradeReplication {
+            id,
+            strategy_id,
+            original_order_id,
+            follower_order_id: 0,
+            follower_id: follower_id.clone(),
+            scale_factor,
+            status: ReplicationStatus::Pending,
+            created_at: now,
+            not_before: now.saturating_add(delay_micros),
+            settled_at: None,
+        };
+        self.index_replication(&replication).await;
 
-            // Store replicated order
-            let _ = self.state.orders.insert(&follower_order_id, replicated_order);
+        let mut replicated_order = match copy {
+            Ok(order) => order,
+            Err(reason) => {
+                replication.settle(ReplicationStatus::Skipped { reason: reason.clone() }, now);
+                let _ = self.state.trade_replications.insert(&id, replication);
 
-            // Store replication record
-            let replication = TradeReplication {
-                original_order_id,
-                follower_order_id,
-                follower_id: follower_id.clone(),
-                scale_factor,
-                status: ReplicationStatus::Executed,
-            };
-            let _ = self.state.trade_replications.insert(&follower_order_id, replication);
+                // Emit event
+                let event = Event::TradeReplicationSkipped { original_order_id, follower_id, reason };
+                let stream_name = StreamName::from(bcs::to_bytes(&"trade_replication_skipped").unwrap());
+                self.runtime.emit(stream_name, &event);
+                return id;
+            }
+        };
 
-            // Emit event
-            let event = Event::TradeReplicated {
-                original_order_id,
-                follower_order_id,
-                follower_id,
+        // Create replicated order: a fresh, unfilled copy outside the
+        // leader's bracket
+        let follower_order_id = *self.state.order_counter.get() + 1;
+        self.state.order_counter.set(follower_order_id);
+        replicated_order.id = follower_order_id;
+        replicated_order.quantity = original_order.quantity * scale_factor;
+        replicated_order.status = OrderStatus::Pending;
+        replicated_order.tx_hash = None;
+        replicated_order.fill_price = None;
+        replicated_order.filled_at = None;
+        replicated_order.created_at = now;
+        replicated_order.parent_order_id = None;
+        // The deadline runs from when the copy may first fill
+        replicated_order.time_in_force = original_order
+            .time_in_force
+            .delayed(original_order.created_at, replication.not_before);
+        replicated_order.expires_at = replicated_order
+            .time_in_force
+            .expires_at(replication.not_before, self.immediate_order_window());
+
+        // Store replicated order
+        let _ = self.state.orders.insert(&follower_order_id, replicated_order);
+
+        // Store replication record; it settles when the follower order
+        // fills, fails, is cancelled or expires
+        replication.follower_order_id = follower_order_id;
+        let _ = self.state.trade_replications.insert(&id, replication);
+        let _ = self.state.replication_by_order.insert(&follower_order_id, id);
+
+        // Emit event
+        let event = Event::TradeReplicated {
+            original_order_id,
+            follower_order_id,
+            follower_id,
+        };
+        let stream_name = StreamName::from(bcs::to_bytes(&"trade_replicated").unwrap());
+        self.runtime.emit(stream_name, &event);
+        id
+    }
+
+    /// The replication that created a follower order, if any
+    async fn replication_of(&self, follower_order_id: u64) -> Option<TradeReplication> {
+        let id = self.state.replication_by_order.get(&follower_order_id).await.ok().flatten()?;
+        self.state.trade_replications.get(&id).await.ok().flatten()
+    }
+
+    /// Whether `follower_id` already has a replication of the leader order
+    async fn is_replicated_for(&self, original_order_id: u64, follower_id: &String) -> bool {
+        let ids = match self.state.replications_by_original.try_load_entry(&original_order_id).await {
+            Ok(Some(ids)) => ids.indices().await.unwrap_or_default(),
+            _ => return false,
+        };
+        for id in ids {
+            if let Ok(Some(replication)) = self.state.trade_replications.get(&id).await {
+                if &replication.follower_id == follower_id {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Add a replication to the by-original, by-follower and by-strategy indexes
+    async fn index_replication(&mut self, replication: &TradeReplication) {
+        if let Ok(ids) = self.state.replications_by_original.load_entry_mut(&replication.original_order_id).await {
+            let _ = ids.insert(&replication.id);
+        }
+        if let Ok(ids) = self.state.replications_by_follower.load_entry_mut(&replication.follower_id).await {
+            let _ = ids.insert(&replication.id);
+        }
+        if let Ok(ids) = self.state.replications_by_strategy.load_entry_mut(&replication.strategy_id).await {
+            let _ = ids.insert(&replication.id);
+        }
+    }
+
+    /// Settle the pending replication behind a follower order, if any
+    async fn settle_replication(&mut self, follower_order_id: u64, status: ReplicationStatus) {
+        let Some(mut replication) = self.replication_of(follower_order_id).await else {
+            return;
+        };
+        if !replication.settle(status.clone(), self.runtime.system_time().micros()) {
+            return;
+        }
+        let replication_id = replication.id;
+        let _ = self.state.trade_replications.insert(&replication_id, replication);
+
+        let event = Event::ReplicationSettled { replication_id, follower_order_id, status };
+        let stream_name = StreamName::from(bcs::to_bytes(&"replication_settled").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
+
+    // ============================================
+    // STRATEGY FEES
+    // ============================================
+
+    /// Start charging a (re)subscribing follower under the strategy's
+    /// current fees. Fees already owed and the high-water mark carry over.
+    async fn open_fee_account(&mut self, strategy_id: u64, follower_id: &str) {
+        let Ok(Some(strategy)) = self.state.strategies.get(&strategy_id).await else {
+            return;
+        };
+        let now = self.runtime.system_time().micros();
+        let key = format!("{}:{}", strategy_id, follower_id);
+        let mut account = match self.state.fee_accounts.get(&key).await.ok().flatten() {
+            Some(account) if account.unfollowed_at.is_none() => return,
+            Some(mut account) => {
+                account.terms = strategy.fees;
+                account.subscription_paid_until = now;
+                account.unfollowed_at = None;
+                account
+            }
+            None => FollowerFeeAccount::new(strategy_id, follower_id.to_string(), strategy.owner, strategy.fees, now),
+        };
+        let charged = account.accrue_subscription(now);
+        self.record_fee(&account, FeeKind::Subscription, charged);
+        let _ = self.state.fee_accounts.insert(&key, account);
+    }
+
+    /// Stop subscription charges once a follower leaves
+    async fn close_fee_account(&mut self, strategy_id: u64, follower_id: &str) {
+        let key = format!("{}:{}", strategy_id, follower_id);
+        let Ok(Some(mut account)) = self.state.fee_accounts.get(&key).await else {
+            return;
+        };
+        if account.unfollowed_at.is_some() {
+            return;
+        }
+        let now = self.runtime.system_time().micros();
+        account.unfollowed_at = Some(now);
+        let charged = account.accrue_subscription(now);
+        self.record_fee(&account, FeeKind::Subscription, charged);
+        let _ = self.state.fee_accounts.insert(&key, account);
+    }
+
+    async fn accrue_follower_fees(&mut self, strategy_id: u64, follower_id: String) {
+        let key = format!("{}:{}", strategy_id, follower_id);
+        let Ok(Some(mut account)) = self.state.fee_accounts.get(&key).await else {
+            return;
+        };
+        let charged = account.accrue_subscription(self.runtime.system_time().micros());
+        self.record_fee(&account, FeeKind::Subscription, charged);
+        let _ = self.state.fee_accounts.insert(&key, account);
+    }
+
+    /// Charge the performance fee on a follower's replicated-trade PnL
+    async fn accrue_performance_fee(&mut self, strategy_id: u64, follower_id: &str, pnl: f64) {
+        let key = format!("{}:{}", strategy_id, follower_id);
+        let Ok(Some(mut account)) = self.state.fee_accounts.get(&key).await else {
+            return;
+        };
+        let charged = account.record_pnl(pnl);
+        self.record_fee(&account, FeeKind::Performance, charged);
+        let _ = self.state.fee_accounts.insert(&key, account);
+    }
+
+    async fn claim_follower_fees(&mut self, strategy_id: u64, follower_id: String) {
+        if !self.is_strategy_owner(strategy_id).await {
+            return;
+        }
+        let key = format!("{}:{}", strategy_id, follower_id);
+        let Ok(Some(mut account)) = self.state.fee_accounts.get(&key).await else {
+            return;
+        };
+        let charged = account.accrue_subscription(self.runtime.system_time().micros());
+        self.record_fee(&account, FeeKind::Subscription, charged);
+        let amount = account.claim();
+        if amount <= 0.0 {
+            let _ = self.state.fee_accounts.insert(&key, account);
+            return;
+        }
+        self.record_fee(&account, FeeKind::Claim, amount);
+        let _ = self.state.fee_accounts.insert(&key, account);
+
+        // Emit event
+        let event = Event::FeesClaimed { strategy_id, follower_id, amount };
+        let stream_name = StreamName::from(bcs::to_bytes(&"fees_claimed").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
+
+    /// Append a non-zero fee movement to the ledger
+    fn record_fee(&mut self, account: &FollowerFeeAccount, kind: FeeKind, amount: f64) {
+        if amount <= 0.0 {
+            return;
+        }
+        let id = *self.state.fee_ledger_counter.get() + 1;
+        self.state.fee_ledger_counter.set(id);
+        let entry = FeeLedgerEntry {
+            id,
+            strategy_id: account.strategy_id,
+            follower_id: account.follower_id.clone(),
+            author: account.author.clone(),
+            kind,
+            amount,
+            at: self.runtime.system_time().micros(),
+        };
+        let _ = self.state.fee_ledger.insert(&id, entry);
+
+        if kind != FeeKind::Claim {
+            let event = Event::FeesAccrued {
+                strategy_id: account.strategy_id,
+                follower_id: account.follower_id.clone(),
+                kind,
+                amount,
             };
-            let stream_name = StreamName::from(bcs::to_bytes(&"trade_replicated").unwrap());
+            let stream_name = StreamName::from(bcs::to_bytes(&"fees_accrued").unwrap());
             self.runtime.emit(stream_name, &event);
         }
     }
@@ -443,36 +2061,21 @@ impl LineraTradeContract {
             // Get safety config for owner
             let safety_config = self.state.safety_configs.get(&owner).await.ok().flatten();
 
-            let mut checks_passed = Vec::new();
-            let mut checks_failed = Vec::new();
-            let mut validation_status = ValidationStatus::Approved;
-
-            if let Some(config) = safety_config {
-                // Check position size
-                if order.quantity <= config.max_position_per_token {
-                    checks_passed.push("position_size".to_string());
-                } else {
-                    checks_failed.push("position_size_exceeded".to_string());
-                    validation_status = ValidationStatus::Rejected {
-                        reason: format!("Position {} exceeds max {}", order.quantity, config.max_position_per_token),
-                    };
-                }
-
-                // Check stop-loss requirement
-                if config.require_stop_loss {
-                    // Note: would need stop_loss field on Order
-                    checks_passed.push("stop_loss_check".to_string());
-                }
-            } else {
-                checks_passed.push("no_safety_config".to_string());
-            }
+            let check = match safety_config {
+                Some(config) => config.check_order(&order),
+                None => SafetyCheck {
+                    checks_passed: vec!["no_safety_config".to_string()],
+                    ..SafetyCheck::default()
+                },
+            };
+            let validation_status = check.status.clone();
 
             // Store validation result
             let validated = ValidatedOrder {
                 order_id,
                 validation_status: validation_status.clone(),
-                checks_passed,
-                checks_failed,
+                checks_passed: check.checks_passed,
+                checks_failed: check.checks_failed,
                 validated_at: self.runtime.system_time().micros(),
             };
             let _ = self.state.validated_orders.insert(&order_id, validated);
@@ -552,46 +2155,133 @@ impl LineraTradeContract {
     // PHASE 2: STRATEGY ENHANCEMENT METHODS
     // ============================================
 
-    async fn update_strategy(&mut self, mut strategy: Strategy, change_reason: Option<String>) {
-        let strategy_id = strategy.id;
-        
-        // Get current strategy to save as version history
-        if let Ok(Some(current)) = self.state.strategies.get(&strategy_id).await {
-            let current_version = current.version;
-            
-            // Save current version to history
-            let version_key = format!("{}:{}", strategy_id, current_version);
-            let version_entry = StrategyVersion {
-                strategy_id,
-                version: current_version,
-                strategy_snapshot: current,
-                changed_at: self.runtime.system_time().micros(),
-                change_reason,
-            };
-            let _ = self.state.strategy_versions.insert(&version_key, version_entry);
-            
-            // Increment version and update timestamp
-            strategy.version = current_version + 1;
-            strategy.updated_at = Some(self.runtime.system_time().micros());
-            
-            // Store updated strategy
-            let _ = self.state.strategies.insert(&strategy_id, strategy.clone());
-            
-            // Emit event
-            let event = Event::StrategyUpdated { 
-                strategy_id, 
-                new_version: strategy.version 
-            };
-            let stream_name = StreamName::from(bcs::to_bytes(&"strategy_updated").unwrap());
+    async fn update_strategy(&mut self, strategy: Strategy, change_reason: Option<String>) {
+        if !strategy.fees.is_valid() || !strategy.rules_are_valid() || !self.is_strategy_owner(strategy.id).await {
+            return;
+        }
+        if let Ok(Some(current)) = self.state.strategies.get(&strategy.id).await {
+            self.commit_strategy_version(current, strategy, change_reason).await;
+        }
+    }
+
+    /// Restore an earlier version's rules as a new version, keeping the
+    /// strategy's live state (active flag, followers)
+    async fn rollback_strategy(&mut self, strategy_id: u64, to_version: u64) {
+        if !self.is_strategy_owner(strategy_id).await {
+            return;
+        }
+        let Ok(Some(current)) = self.state.strategies.get(&strategy_id).await else {
+            return;
+        };
+        if to_version >= current.version {
+            return;
+        }
+        let version_key = format!("{}:{}", strategy_id, to_version);
+        let Ok(Some(entry)) = self.state.strategy_versions.get(&version_key).await else {
+            return;
+        };
+
+        let mut restored = entry.strategy_snapshot;
+        restored.active = current.active;
+        let change_reason = Some(format!("rollback to v{}", to_version));
+        let new_version = self.commit_strategy_version(current, restored, change_reason).await;
+
+        let event = Event::StrategyRolledBack { strategy_id, to_version, new_version };
+        let stream_name = StreamName::from(bcs::to_bytes(&"strategy_rolled_back").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
+
+    /// Store `proposed` as the version after `current` and add it to the
+    /// version history. Returns the new version number.
+    async fn commit_strategy_version(
+        &mut self,
+        current: Strategy,
+        proposed: Strategy,
+        change_reason: Option<String>,
+    ) -> u64 {
+        let strategy_id = current.id;
+        let now = self.runtime.system_time().micros();
+
+        // Strategies created before v1 was recorded at creation
+        let current_key = format!("{}:{}", strategy_id, current.version);
+        if !self.state.strategy_versions.contains_key(&current_key).await.unwrap_or(true) {
+            let changed_at = current.updated_at.unwrap_or(current.created_at);
+            self.record_strategy_version(current.clone(), changed_at, None);
+        }
+
+        let next = current.next_version(proposed, now);
+        let risk_changed = next.risk_version == next.version;
+        let new_version = next.version;
+        self.record_strategy_version(next.clone(), now, change_reason);
+        let _ = self.state.strategies.insert(&strategy_id, next);
+
+        // Emit event
+        let event = Event::StrategyUpdated { strategy_id, new_version };
+        let stream_name = StreamName::from(bcs::to_bytes(&"strategy_updated").unwrap());
+        self.runtime.emit(stream_name, &event);
+        if risk_changed {
+            let event = Event::StrategyRiskChanged { strategy_id, version: new_version };
+            let stream_name = StreamName::from(bcs::to_bytes(&"strategy_risk_changed").unwrap());
             self.runtime.emit(stream_name, &event);
         }
+        new_version
+    }
+
+    /// Record the rules behind a committed version once they match its commitment
+    async fn reveal_strategy_rules(&mut self, strategy_id: u64, version: u64, strategy_type: StrategyType, salt: String) {
+        let Some(revealed_by) = self.runtime.authenticated_signer().map(|owner| owner.to_string()) else {
+            return;
+        };
+        let version_key = format!("{}:{}", strategy_id, version);
+        if strategy_type == StrategyType::Hidden
+            || self.state.rules_reveals.contains_key(&version_key).await.unwrap_or(true)
+        {
+            return;
+        }
+        let Ok(Some(entry)) = self.state.strategy_versions.get(&version_key).await else {
+            return;
+        };
+        match entry.strategy_snapshot.rules_commitment {
+            Some(commitment) if commitment == rules_commitment(&strategy_type, &salt) => {}
+            _ => return,
+        }
+
+        let reveal = RulesReveal {
+            strategy_id,
+            version,
+            strategy_type,
+            salt,
+            revealed_by,
+            revealed_at: self.runtime.system_time().micros(),
+        };
+        let _ = self.state.rules_reveals.insert(&version_key, reveal);
+
+        let event = Event::StrategyRulesRevealed { strategy_id, version };
+        let stream_name = StreamName::from(bcs::to_bytes(&"strategy_rules_revealed").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
+
+    fn record_strategy_version(&mut self, strategy: Strategy, changed_at: u64, change_reason: Option<String>) {
+        let version_key = format!("{}:{}", strategy.id, strategy.version);
+        let version_entry = StrategyVersion {
+            strategy_id: strategy.id,
+            version: strategy.version,
+            strategy_snapshot: strategy,
+            changed_at,
+            change_reason,
+        };
+        let _ = self.state.strategy_versions.insert(&version_key, version_entry);
     }
 
     // ============================================
     // PHASE 3: EXECUTION ENGINE METHODS
     // ============================================
 
-    async fn create_multi_hop_order(&mut self, mut order: DEXOrder) {
+    async fn create_multi_hop_order(&mut self, mut order: DEXOrder) -> u64 {
+        if !self.plan_order_slices(&mut order) {
+            return 0;
+        }
+
         // Generate ID
         let id = *self.state.dex_order_counter.get() + 1;
         order.id = id;
@@ -612,7 +2302,7 @@ impl LineraTradeContract {
             }
             
             if !valid_route {
-                return; // Invalid route, don't create order
+                return 0; // Invalid route, don't create order
             }
         }
 
@@ -623,6 +2313,7 @@ impl LineraTradeContract {
         let event = Event::MultiHopOrderCreated { order_id: id, hop_count };
         let stream_name = StreamName::from(bcs::to_bytes(&"multi_hop_order_created").unwrap());
         self.runtime.emit(stream_name, &event);
+        id
     }
 
     async fn check_conditional_orders(&mut self) {
@@ -633,6 +2324,12 @@ impl LineraTradeContract {
 
     async fn trigger_conditional_order(&mut self, order_id: u64) {
         if let Ok(Some(mut order)) = self.state.dex_orders.get(&order_id).await {
+            if self.expire_dex_order_if_due(&mut order).await {
+                return;
+            }
+            if !order.status.is_open() || !self.is_strategy_owner(order.strategy_id).await {
+                return;
+            }
             // Check if order has conditional trigger
             if let Some(ref mut trigger) = order.conditional_trigger {
                 if trigger.active {
@@ -653,6 +2350,12 @@ impl LineraTradeContract {
 
     async fn cancel_conditional_order(&mut self, order_id: u64) {
         if let Ok(Some(mut order)) = self.state.dex_orders.get(&order_id).await {
+            if self.expire_dex_order_if_due(&mut order).await {
+                return;
+            }
+            if !order.status.is_open() || !self.is_strategy_owner(order.strategy_id).await {
+                return;
+            }
             if let Some(ref mut trigger) = order.conditional_trigger {
                 trigger.active = false;
             }
@@ -666,15 +2369,361 @@ impl LineraTradeContract {
         }
     }
 
-    async fn create_microchain_profile(&mut self, name: String, wallet: String, chains: Vec<String>, visibility: String) {
+    async fn create_split_route_order(&mut self, mut order: DEXOrder) -> u64 {
+        if order.validate_split_route().is_err() || order.execution_mode.is_sliced() {
+            return 0; // Invalid split route, don't create order
+        }
+        if !self.set_dex_order_expiry(&mut order) {
+            return 0;
+        }
+
+        // Generate ID
+        let id = *self.state.dex_order_counter.get() + 1;
+        order.id = id;
+        self.state.dex_order_counter.set(id);
+
+        let expected_output = order.expected_split_output();
+        let leg_count = order.split_legs.len();
+        order.output_amount = expected_output;
+        order.is_multi_hop = order.split_legs.iter().any(|leg| leg.hops.len() > 1);
+        for leg in order.split_legs.iter_mut() {
+            leg.tx_signature = None;
+            leg.output_amount = None;
+        }
+        order.slices.clear();
+        order.paused = false;
+
+        // Store order
+        let _ = self.state.dex_orders.insert(&id, order);
+
+        // Emit event
+        let event = Event::SplitRouteOrderCreated { order_id: id, leg_count, expected_output };
+        let stream_name = StreamName::from(bcs::to_bytes(&"split_route_order_created").unwrap());
+        self.runtime.emit(stream_name, &event);
+        id
+    }
+
+    async fn execute_split_route_order(&mut self, order_id: u64, leg_fills: Vec<LegFill>) {
+        if let Ok(Some(mut order)) = self.state.dex_orders.get(&order_id).await {
+            if self.expire_dex_order_if_due(&mut order).await {
+                return;
+            }
+            if !order.is_split() || !order.status.is_open() {
+                return;
+            }
+
+            let output_amount = match order.apply_leg_fills(&leg_fills) {
+                Ok(output_amount) => output_amount,
+                Err(error) => {
+                    let event = Event::DEXOrderFailed { order_id, reason: error.to_string() };
+                    let stream_name = StreamName::from(bcs::to_bytes(&"dex_order_failed").unwrap());
+                    self.runtime.emit(stream_name, &event);
+                    return;
+                }
+            };
+
+            order.status = OrderStatus::Filled;
+            order.output_amount = output_amount;
+            order.executed_at = Some(self.runtime.system_time().micros());
+            let _ = self.state.dex_orders.insert(&order_id, order);
+
+            // Emit event
+            let event = Event::SplitRouteOrderExecuted { order_id, leg_fills, output_amount };
+            let stream_name = StreamName::from(bcs::to_bytes(&"split_route_order_executed").unwrap());
+            self.runtime.emit(stream_name, &event);
+        }
+    }
+
+    /// Plan child slices for TWAP/VWAP/iceberg orders; other modes carry none.
+    /// Returns false if the order must be rejected.
+    fn plan_order_slices(&mut self, order: &mut DEXOrder) -> bool {
+        // Split legs are validated and set up by `CreateSplitRouteOrder` only
+        if order.is_split() || !self.set_dex_order_expiry(order) {
+            return false;
+        }
+        order.paused = false;
+        if !order.execution_mode.is_sliced() {
+            order.slices.clear();
+            return true;
+        }
+        // Fill-or-kill cannot be split into independently filled slices
+        if order.time_in_force == TimeInForce::FillOrKill {
+            return false;
+        }
+        let start = self.runtime.system_time().micros();
+        match order.execution_mode.plan_slices(order.input_amount, start) {
+            Some(slices) => {
+                order.slices = slices;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Compute a new DEX order's expiry from its time in force. Returns false
+    /// if the order would already be expired.
+    fn set_dex_order_expiry(&mut self, order: &mut DEXOrder) -> bool {
+        let now = self.runtime.system_time().micros();
+        order.expires_at = order.time_in_force.expires_at(now, self.immediate_order_window());
+        if !is_expired(order.expires_at, &order.status, now) {
+            return true;
+        }
+        let event = Event::DEXOrderRejected {
+            strategy_id: order.strategy_id,
+            reason: "already expired".to_string(),
+        };
+        let stream_name = StreamName::from(bcs::to_bytes(&"dex_order_rejected").unwrap());
+        self.runtime.emit(stream_name, &event);
+        false
+    }
+
+    /// Move an overdue order to `Expired`. Returns true if it expired.
+    async fn expire_order_if_due(&mut self, order: &mut Order) -> bool {
+        let now = self.runtime.system_time().micros();
+        if !is_expired(order.expires_at, &order.status, now) {
+            return false;
+        }
+        order.status = OrderStatus::Expired;
+        let _ = self.state.orders.insert(&order.id, order.clone());
+
+        let event = Event::OrderExpired { order_id: order.id };
+        let stream_name = StreamName::from(bcs::to_bytes(&"order_expired").unwrap());
+        self.runtime.emit(stream_name, &event);
+        self.settle_replication(order.id, ReplicationStatus::Failed { reason: "expired".to_string() }).await;
+        true
+    }
+
+    /// Move an overdue DEX order and its unfilled slices to `Expired`.
+    /// Returns true if it expired.
+    async fn expire_dex_order_if_due(&mut self, order: &mut DEXOrder) -> bool {
+        let now = self.runtime.system_time().micros();
+        if !is_expired(order.expires_at, &order.status, now) {
+            return false;
+        }
+        order.status = OrderStatus::Expired;
+        for slice in order.slices.iter_mut() {
+            if slice.status.is_open() {
+                slice.status = OrderStatus::Expired;
+            }
+        }
+        if let Some(ref mut trigger) = order.conditional_trigger {
+            trigger.active = false;
+        }
+        let _ = self.state.dex_orders.insert(&order.id, order.clone());
+
+        let event = Event::DEXOrderExpired { order_id: order.id };
+        let stream_name = StreamName::from(bcs::to_bytes(&"dex_order_expired").unwrap());
+        self.runtime.emit(stream_name, &event);
+        true
+    }
+
+    /// Expire overdue orders in bounded batches, resuming from the previous
+    /// sweep's position and wrapping around at the end
+    async fn sweep_expired_orders(&mut self, limit: u32) {
+        let limit = limit.min(MAX_EXPIRY_SWEEP) as u64;
+
+        let counter = *self.state.order_counter.get();
+        let mut cursor = *self.state.order_expiry_cursor.get();
+        for _ in 0..limit.min(counter) {
+            cursor = if cursor >= counter { 1 } else { cursor + 1 };
+            if let Ok(Some(mut order)) = self.state.orders.get(&cursor).await {
+                self.expire_order_if_due(&mut order).await;
+            }
+        }
+        self.state.order_expiry_cursor.set(cursor);
+
+        let counter = *self.state.dex_order_counter.get();
+        let mut cursor = *self.state.dex_order_expiry_cursor.get();
+        for _ in 0..limit.min(counter) {
+            cursor = if cursor >= counter { 1 } else { cursor + 1 };
+            if let Ok(Some(mut order)) = self.state.dex_orders.get(&cursor).await {
+                self.expire_dex_order_if_due(&mut order).await;
+            }
+        }
+        self.state.dex_order_expiry_cursor.set(cursor);
+    }
+
+    /// Whether the authenticated signer owns the given strategy
+    async fn is_strategy_owner(&mut self, strategy_id: u64) -> bool {
+        let Some(signer) = self.runtime.authenticated_signer() else {
+            return false;
+        };
+        matches!(
+            self.state.strategies.get(&strategy_id).await,
+            Ok(Some(strategy)) if strategy.owner == signer.to_string()
+        )
+    }
+
+    async fn execute_order_slice(
+        &mut self,
+        order_id: u64,
+        slice_index: u32,
+        tx_signature: String,
+        output_amount: u64,
+    ) {
+        if self.is_fill_replay("dex_order", order_id, &tx_signature).await {
+            return;
+        }
+        let now = self.runtime.system_time().micros();
+        if let Ok(Some(mut order)) = self.state.dex_orders.get(&order_id).await {
+            if self.expire_dex_order_if_due(&mut order).await {
+                return;
+            }
+            // Only the slice currently due may be filled
+            if order.due_slice(now).map(|slice| slice.index) != Some(slice_index) {
+                return;
+            }
+
+            let slice = &mut order.slices[slice_index as usize];
+            slice.status = OrderStatus::Filled;
+            slice.tx_signature = Some(tx_signature.clone());
+            slice.output_amount = Some(output_amount);
+            slice.executed_at = Some(now);
+
+            // Iceberg slices are released one after another as they fill
+            if matches!(order.execution_mode, ExecutionMode::Iceberg { .. }) {
+                if let Some(next) = order.slices.get_mut(slice_index as usize + 1) {
+                    next.due_at = now;
+                }
+            }
+
+            order.output_amount = order.filled_output();
+            let completed = order.slices.iter().all(|slice| matches!(slice.status, OrderStatus::Filled));
+            if completed {
+                order.status = OrderStatus::Filled;
+                order.tx_signature = Some(tx_signature.clone());
+                order.executed_at = Some(now);
+            } else {
+                order.status = OrderStatus::Submitted;
+            }
+            let total_output = order.output_amount;
+            let _ = self.state.dex_orders.insert(&order_id, order);
+            self.remember_fill("dex_order", order_id, &tx_signature);
+
+            // Emit event
+            let event = Event::OrderSliceExecuted {
+                order_id,
+                slice_index,
+                tx_signature: tx_signature.clone(),
+                output_amount,
+            };
+            let stream_name = StreamName::from(bcs::to_bytes(&"order_slice_executed").unwrap());
+            self.runtime.emit(stream_name, &event);
+
+            if completed {
+                let event = Event::DEXOrderExecuted {
+                    order_id,
+                    tx_signature,
+                    output_amount: total_output,
+                };
+                let stream_name = StreamName::from(bcs::to_bytes(&"dex_order_executed").unwrap());
+                self.runtime.emit(stream_name, &event);
+            }
+        }
+    }
+
+    async fn set_dex_order_paused(&mut self, order_id: u64, paused: bool) {
+        if let Ok(Some(mut order)) = self.state.dex_orders.get(&order_id).await {
+            if self.expire_dex_order_if_due(&mut order).await {
+                return;
+            }
+            if !order.is_sliced()
+                || order.paused == paused
+                || !matches!(order.status, OrderStatus::Pending | OrderStatus::Submitted)
+                || !self.is_strategy_owner(order.strategy_id).await
+            {
+                return;
+            }
+            order.paused = paused;
+            let _ = self.state.dex_orders.insert(&order_id, order);
+
+            // Emit event
+            let (event, stream) = if paused {
+                (Event::DEXOrderPaused { order_id }, "dex_order_paused")
+            } else {
+                (Event::DEXOrderResumed { order_id }, "dex_order_resumed")
+            };
+            let stream_name = StreamName::from(bcs::to_bytes(&stream).unwrap());
+            self.runtime.emit(stream_name, &event);
+        }
+    }
+
+    async fn cancel_dex_order_remainder(&mut self, order_id: u64) {
+        if let Ok(Some(mut order)) = self.state.dex_orders.get(&order_id).await {
+            if self.expire_dex_order_if_due(&mut order).await {
+                return;
+            }
+            if !order.is_sliced()
+                || !matches!(order.status, OrderStatus::Pending | OrderStatus::Submitted)
+                || !self.is_strategy_owner(order.strategy_id).await
+            {
+                return;
+            }
+
+            let mut cancelled_slices = 0;
+            for slice in order.slices.iter_mut() {
+                if matches!(slice.status, OrderStatus::Pending) {
+                    slice.status = OrderStatus::Cancelled;
+                    cancelled_slices += 1;
+                }
+            }
+            order.status = OrderStatus::Cancelled;
+            let filled_input = order.filled_input();
+            let _ = self.state.dex_orders.insert(&order_id, order);
+
+            // Emit event
+            let event = Event::DEXOrderRemainderCancelled { order_id, cancelled_slices, filled_input };
+            let stream_name = StreamName::from(bcs::to_bytes(&"dex_order_remainder_cancelled").unwrap());
+            self.runtime.emit(stream_name, &event);
+        }
+    }
+
+    async fn create_microchain_profile(
+        &mut self,
+        name: String,
+        wallet: String,
+        chains: Vec<String>,
+        visibility: ProfileVisibility,
+        signature: AccountSignature,
+    ) {
+        // A wallet belongs to at most one profile at a time
+        if self.profile_id_of(&wallet).await.is_some()
+            || self.state.microchain_profiles.contains_key(&wallet).await.unwrap_or(true)
+        {
+            return;
+        }
+        // A profile chain hosts the one profile it was opened for
+        if self.state.registry_chain.get().is_some() {
+            return;
+        }
+        // The wallet must agree, or anyone could claim it first. A deleted
+        // profile's nonce carries over, so its old proofs stay spent.
+        let nonce = self.next_profile_nonce(&wallet).await;
+        if !self.wallet_link_proof(wallet.clone(), wallet.clone(), nonce).is_signed_by_wallet(&signature) {
+            return;
+        }
+        let signer = self.runtime.authenticated_signer();
+        // The profile chain is owned by the signer, so registry mode needs one
+        let registry_mode = *self.state.registry_mode.get();
+        if registry_mode && signer.is_none() {
+            return;
+        }
+        let owner = signer
+            .map(|owner| owner.to_string())
+            .unwrap_or_else(|| wallet.clone());
+
         // Create profile with performance tracking fields
-        let profile = MicrochainProfile {
+        let mut profile = MicrochainProfile {
             id: wallet.clone(), // Use wallet as ID
             name: name.clone(),
             wallets: vec![wallet.clone()],
             preferred_chains: chains,
             visibility,
             created_at: self.runtime.system_time().micros(),
+            owner,
+            updated_at: None,
+            link_nonce: nonce + 1,
+            chain_id: None,
             // Initialize performance tracking
             total_trades: 0,
             winning_trades: 0,
@@ -682,8 +2731,32 @@ impl LineraTradeContract {
             total_pnl: 0,
         };
 
+        // In registry mode the trader gets a chain of their own, funded from
+        // this chain with the configured balance (creation fails if this
+        // chain cannot pay it), and this chain keeps the directory entry
+        if registry_mode {
+            if let Some(signer) = signer {
+                let chain_id = self.runtime.open_chain(
+                    ChainOwnership::single(signer),
+                    ApplicationPermissions::default(),
+                    *self.state.profile_chain_balance.get(),
+                );
+                profile.chain_id = Some(chain_id);
+                let message = Message::InitProfileChain {
+                    profile: profile.clone(),
+                    admin: self.state.admin.get().clone(),
+                };
+                self.runtime.prepare_message(message).with_tracking().send_to(chain_id);
+
+                let event = Event::ProfileChainOpened { profile_id: wallet.clone(), chain_id };
+                let stream_name = StreamName::from(bcs::to_bytes(&"profile_chain_opened").unwrap());
+                self.runtime.emit(stream_name, &event);
+            }
+        }
+
         // Store profile
         let _ = self.state.microchain_profiles.insert(&wallet, profile);
+        let _ = self.state.wallet_profiles.insert(&wallet, wallet.clone());
 
         // Increment microchain counter for analytics
         let current_count = *self.state.microchain_counter.get();
@@ -694,4 +2767,262 @@ impl LineraTradeContract {
         let stream_name = StreamName::from(bcs::to_bytes(&"microchain_profile_created").unwrap());
         self.runtime.emit(stream_name, &event);
     }
+
+    async fn update_microchain_profile(
+        &mut self,
+        profile_id: String,
+        name: Option<String>,
+        chains: Option<Vec<String>>,
+        visibility: Option<ProfileVisibility>,
+    ) {
+        let Some(mut profile) = self.managed_profile(&profile_id).await else {
+            return;
+        };
+        if let Some(name) = name {
+            profile.name = name;
+        }
+        if let Some(chains) = chains {
+            profile.preferred_chains = chains;
+        }
+        if let Some(visibility) = visibility {
+            profile.visibility = visibility;
+        }
+        profile.updated_at = Some(self.runtime.system_time().micros());
+        let _ = self.state.microchain_profiles.insert(&profile_id, profile.clone());
+        self.report_to_registry(Message::ProfileUpdated { profile });
+
+        let event = Event::MicrochainProfileUpdated { profile_id };
+        let stream_name = StreamName::from(bcs::to_bytes(&"microchain_profile_updated").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
+
+    async fn link_wallet(&mut self, profile_id: String, wallet: String, signature: AccountSignature) {
+        let Some(mut profile) = self.managed_profile(&profile_id).await else {
+            return;
+        };
+        if self.profile_id_of(&wallet).await.is_some() {
+            return;
+        }
+        let proof = self.wallet_link_proof(profile_id.clone(), wallet.clone(), profile.link_nonce);
+        if !proof.is_signed_by_wallet(&signature) {
+            return;
+        }
+
+        profile.wallets.push(wallet.clone());
+        profile.link_nonce += 1;
+        profile.updated_at = Some(self.runtime.system_time().micros());
+        let _ = self.state.microchain_profiles.insert(&profile_id, profile.clone());
+        self.report_to_registry(Message::ProfileUpdated { profile });
+        let _ = self.state.wallet_profiles.insert(&wallet, profile_id.clone());
+
+        let event = Event::WalletLinked { profile_id, wallet };
+        let stream_name = StreamName::from(bcs::to_bytes(&"wallet_linked").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
+
+    async fn unlink_wallet(&mut self, profile_id: String, wallet: String) {
+        let Some(mut profile) = self.managed_profile(&profile_id).await else {
+            return;
+        };
+        if wallet == profile.id || !profile.wallets.contains(&wallet) {
+            return;
+        }
+
+        profile.wallets.retain(|linked| linked != &wallet);
+        profile.updated_at = Some(self.runtime.system_time().micros());
+        let _ = self.state.microchain_profiles.insert(&profile_id, profile.clone());
+        self.report_to_registry(Message::ProfileUpdated { profile });
+        let _ = self.state.wallet_profiles.remove(&wallet);
+
+        let event = Event::WalletUnlinked { profile_id, wallet };
+        let stream_name = StreamName::from(bcs::to_bytes(&"wallet_unlinked").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
+
+    /// Remove a profile and free its wallets. Trading statistics kept per
+    /// wallet are not affected.
+    async fn delete_microchain_profile(&mut self, profile_id: String) {
+        let Some(profile) = self.managed_profile(&profile_id).await else {
+            return;
+        };
+        for wallet in &profile.wallets {
+            let _ = self.state.wallet_profiles.remove(wallet);
+        }
+        let _ = self.state.microchain_profiles.remove(&profile_id);
+        let _ = self.state.retired_profile_nonces.insert(&profile_id, profile.link_nonce);
+
+        let current_count = *self.state.microchain_counter.get();
+        self.state.microchain_counter.set(current_count.saturating_sub(1));
+        self.report_to_registry(Message::ProfileDeleted { profile_id: profile_id.clone() });
+
+        let event = Event::MicrochainProfileDeleted { profile_id };
+        let stream_name = StreamName::from(bcs::to_bytes(&"microchain_profile_deleted").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
+
+    /// Nonce the proof creating a profile for `wallet` must carry: 0 for a
+    /// new id, or the deleted profile's next link nonce
+    async fn next_profile_nonce(&self, wallet: &String) -> u64 {
+        self.state.retired_profile_nonces.get(wallet).await.ok().flatten().unwrap_or(0)
+    }
+
+    fn wallet_link_proof(&mut self, profile_id: String, wallet: String, nonce: u64) -> WalletLinkProof {
+        WalletLinkProof {
+            chain_id: self.runtime.chain_id(),
+            application_id: self.runtime.application_id().forget_abi(),
+            profile_id,
+            wallet,
+            nonce,
+        }
+    }
+
+    /// The profile a wallet is linked to. Profiles created before the
+    /// wallet index are found by their id.
+    async fn profile_id_of(&self, wallet: &String) -> Option<String> {
+        if let Ok(Some(profile_id)) = self.state.wallet_profiles.get(wallet).await {
+            return Some(profile_id);
+        }
+        match self.state.microchain_profiles.get(wallet).await {
+            Ok(Some(profile)) => Some(profile.id),
+            _ => None,
+        }
+    }
+
+    /// The profile, if the authenticated signer may manage it here. A
+    /// profile with its own chain is managed on that chain.
+    async fn managed_profile(&mut self, profile_id: &String) -> Option<MicrochainProfile> {
+        let signer = self.runtime.authenticated_signer()?.to_string();
+        let profile = self.state.microchain_profiles.get(profile_id).await.ok().flatten()?;
+        let chain_id = self.runtime.chain_id();
+        (profile.is_managed_by(&signer) && profile.is_hosted_on(chain_id)).then_some(profile)
+    }
+
+    // ============================================
+    // PROFILE CHAIN REGISTRY
+    // ============================================
+
+    fn set_registry_mode(&mut self, enabled: bool) {
+        // Profile chains report to a registry and cannot become one
+        if !self.is_admin() || self.state.registry_chain.get().is_some() {
+            return;
+        }
+        self.state.registry_mode.set(enabled);
+
+        let event = Event::RegistryModeChanged { enabled };
+        let stream_name = StreamName::from(bcs::to_bytes(&"registry_mode_changed").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
+
+    fn set_profile_chain_balance(&mut self, balance: Amount) {
+        if !self.is_admin() {
+            return;
+        }
+        self.state.profile_chain_balance.set(balance);
+    }
+
+    /// On a profile chain, forward a change to the registry chain
+    fn report_to_registry(&mut self, message: Message) {
+        if let Some(registry_chain) = *self.state.registry_chain.get() {
+            self.runtime.send_message(registry_chain, message);
+        }
+    }
+
+    /// First message on a new profile chain: the application starts here
+    /// with the registry's admin and the trader's profile
+    async fn init_profile_chain(&mut self, registry_chain: ChainId, profile: MicrochainProfile, admin: Option<String>) {
+        if self.state.registry_chain.get().is_some() || profile.chain_id != Some(self.runtime.chain_id()) {
+            return;
+        }
+        self.state.registry_chain.set(Some(registry_chain));
+        self.state.admin.set(admin);
+        self.state.max_batch_size.set(DEFAULT_MAX_BATCH_SIZE);
+
+        let profile_id = profile.id.clone();
+        for wallet in &profile.wallets {
+            let _ = self.state.wallet_profiles.insert(wallet, profile_id.clone());
+        }
+        let _ = self.state.microchain_profiles.insert(&profile_id, profile);
+        self.state.microchain_counter.set(1);
+    }
+
+    /// The registry's directory entry, if `chain` hosts it
+    async fn hosted_profile(&self, chain: ChainId, profile_id: &String) -> Option<MicrochainProfile> {
+        let profile = self.state.microchain_profiles.get(profile_id).await.ok().flatten()?;
+        (profile.chain_id == Some(chain)).then_some(profile)
+    }
+
+    async fn sync_profile(&mut self, origin: ChainId, mut profile: MicrochainProfile) {
+        let Some(entry) = self.hosted_profile(origin, &profile.id).await else {
+            return;
+        };
+        // Wallets are indexed here only while no other profile claims them
+        for wallet in &entry.wallets {
+            if !profile.wallets.contains(wallet) {
+                let _ = self.state.wallet_profiles.remove(wallet);
+            }
+        }
+        let mut wallets = Vec::with_capacity(profile.wallets.len());
+        for wallet in profile.wallets {
+            match self.profile_id_of(&wallet).await {
+                Some(owner) if owner != profile.id => continue,
+                _ => {
+                    let _ = self.state.wallet_profiles.insert(&wallet, profile.id.clone());
+                    wallets.push(wallet);
+                }
+            }
+        }
+        profile.wallets = wallets;
+        profile.chain_id = Some(origin);
+        let _ = self.state.microchain_profiles.insert(&entry.id, profile);
+    }
+
+    async fn sync_profile_trade(&mut self, origin: ChainId, owner: String, stats: PerformanceStats, point: EquityPoint) {
+        let Some(profile_id) = self.profile_id_of(&owner).await else {
+            return;
+        };
+        if self.hosted_profile(origin, &profile_id).await.is_none() {
+            return;
+        }
+        let _ = self.state.profile_performance.insert(&owner, stats);
+        let mut curve = self.state.profile_equity.get(&owner).await.ok().flatten().unwrap_or_default();
+        push_equity_point(&mut curve, point);
+        let _ = self.state.profile_equity.insert(&owner, curve);
+    }
+
+    async fn sync_profile_deletion(&mut self, origin: ChainId, profile_id: String) {
+        let Some(entry) = self.hosted_profile(origin, &profile_id).await else {
+            return;
+        };
+        for wallet in &entry.wallets {
+            let _ = self.state.wallet_profiles.remove(wallet);
+        }
+        let _ = self.state.microchain_profiles.remove(&profile_id);
+        let _ = self.state.retired_profile_nonces.insert(&profile_id, entry.link_nonce);
+
+        let current_count = *self.state.microchain_counter.get();
+        self.state.microchain_counter.set(current_count.saturating_sub(1));
+
+        let event = Event::MicrochainProfileDeleted { profile_id };
+        let stream_name = StreamName::from(bcs::to_bytes(&"microchain_profile_deleted").unwrap());
+        self.runtime.emit(stream_name, &event);
+    }
+
+    /// The profile chain was never set up, so the profile is managed here
+    async fn reclaim_profile(&mut self, profile_id: String) {
+        if let Ok(Some(mut profile)) = self.state.microchain_profiles.get(&profile_id).await {
+            profile.chain_id = None;
+            let _ = self.state.microchain_profiles.insert(&profile_id, profile);
+        }
+    }
 }
+
+/// Append to a bounded equity curve, dropping the oldest points
+fn push_equity_point(curve: &mut Vec<EquityPoint>, point: EquityPoint) {
+    curve.push(point);
+    if curve.len() > MAX_EQUITY_POINTS {
+        curve.drain(..curve.len() - MAX_EQUITY_POINTS);
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -4,13 +4,17 @@ mod state;
 
 use linera_sdk::{Service, ServiceRuntime};
 use linera_sdk::abi::WithServiceAbi;
-use abi::{LineraTradeAbi, Order, Signal, Strategy, Query, QueryResponse, SafetyConfig, ValidatedOrder, PredictionMarket, StrategyMarketLink, StrategyVersion, MicrochainProfile, NetworkAnalytics, LeaderboardEntry};
+use abi::{LineraTradeAbi, SignalPolicy, SignalWithOutcome, Order, OrderStatus, SignalOutcome, InfluencerStats, Bracket, DEXOrder, DueSlice, Signal, Strategy, Query, QueryResponse, SafetyConfig, ValidatedOrder, PredictionMarket, StrategyMarketLink, StrategyVersion, MicrochainProfile, NetworkAnalytics, EquityPoint, LeaderboardEntry, LeaderboardSort, PerformanceStats, FollowerFeeAccount, FeeLedgerEntry, FollowSummary, StrategyFollower, ReplicationFilter, TradeReplication, StrategyDiff, ForkNode, ForkTree, CuratorReview, CuratedStrategy, ProfileVisibility, ProfileRegistry};
 use self::state::LineraTradeState;
 
 linera_sdk::service!(LineraTradeService);
 
+/// Entries in the network analytics leaderboard
+const NETWORK_LEADERBOARD_SIZE: usize = 10;
+
 pub struct LineraTradeService {
     state: LineraTradeState,
+    runtime: ServiceRuntime<Self>,
 }
 
 impl WithServiceAbi for LineraTradeService {
@@ -24,7 +28,7 @@ impl Service for LineraTradeService {
         let state = <LineraTradeState as linera_sdk::views::View>::load(runtime.root_view_storage_context())
             .await
             .expect("Failed to load state");
-        LineraTradeService { state }
+        LineraTradeService { state, runtime }
     }
 
     async fn handle_query(&self, query: Query) -> QueryResponse {
@@ -33,12 +37,29 @@ impl Service for LineraTradeService {
                 QueryResponse::Signals(self.get_signals(limit, offset).await)
             }
             Query::GetSignal { id } => QueryResponse::Signal(self.get_signal(id).await),
+            Query::GetSignalOutcome { signal_id } => {
+                QueryResponse::SignalOutcome(self.get_signal_outcome(signal_id).await)
+            }
+            Query::GetSignalFeed { limit, offset } => {
+                QueryResponse::SignalFeed(self.get_signal_feed(limit, offset).await)
+            }
+            Query::GetInfluencers { min_score, min_signals, limit } => {
+                QueryResponse::Influencers(self.get_influencers(min_score, min_signals, limit).await)
+            }
+            Query::GetInfluencer { influencer } => {
+                QueryResponse::Influencer(self.get_influencer(influencer).await)
+            }
+            Query::GetSignalSubmitters => {
+                QueryResponse::SignalSubmitters(self.get_signal_submitters().await)
+            }
+            Query::GetSignalPolicy => QueryResponse::SignalPolicy(self.get_signal_policy()),
             Query::GetStrategies {
                 owner,
                 limit,
                 offset,
-            } => QueryResponse::Strategies(self.get_strategies(owner, limit, offset).await),
-            Query::GetStrategy { id } => QueryResponse::Strategy(self.get_strategy(id).await),
+                viewer,
+            } => QueryResponse::Strategies(self.get_strategies(owner, limit, offset, viewer).await),
+            Query::GetStrategy { id, viewer } => QueryResponse::Strategy(self.get_strategy(id, viewer).await),
             Query::GetOrders {
                 strategy_id,
                 status,
@@ -46,6 +67,13 @@ impl Service for LineraTradeService {
                 offset,
             } => QueryResponse::Orders(self.get_orders(strategy_id, status, limit, offset).await),
             Query::GetOrder { id } => QueryResponse::Order(self.get_order(id).await),
+            Query::GetBracket { entry_order_id } => {
+                QueryResponse::Bracket(self.get_bracket(entry_order_id).await)
+            }
+            Query::GetDEXOrder { id } => QueryResponse::DEXOrder(self.get_dex_order(id).await),
+            Query::GetDueOrderSlices { limit } => {
+                QueryResponse::DueOrderSlices(self.get_due_order_slices(limit).await)
+            }
             // Safety & Validation Queries
             Query::GetSafetyConfig { owner } => {
                 QueryResponse::SafetyConfig(self.get_safety_config(owner).await)
@@ -64,11 +92,86 @@ impl Service for LineraTradeService {
                 QueryResponse::StrategyMarketLinks(self.get_strategy_market_links(strategy_id).await)
             }
             // Strategy Enhancement Queries (Phase 2)
-            Query::GetStrategyVersions { strategy_id } => {
-                QueryResponse::StrategyVersions(self.get_strategy_versions(strategy_id).await)
+            Query::GetStrategyVersions { strategy_id, viewer } => {
+                QueryResponse::StrategyVersions(self.get_strategy_versions(strategy_id, viewer).await)
+            }
+            Query::GetStrategyDiff { strategy_id, from_version, to_version, viewer } => {
+                let diff = self.get_strategy_diff(strategy_id, from_version, to_version, viewer).await;
+                QueryResponse::StrategyDiff(diff)
+            }
+            Query::GetCurators => QueryResponse::Curators(self.state.curators.indices().await.unwrap_or_default()),
+            Query::GetStrategyReviews { strategy_id } => {
+                QueryResponse::StrategyReviews(self.get_strategy_reviews(strategy_id).await)
+            }
+            Query::GetCuratedStrategies { min_rating, limit, offset, viewer } => {
+                let curated = self.get_curated_strategies(min_rating, limit, offset, viewer).await;
+                QueryResponse::CuratedStrategies(curated)
+            }
+            Query::GetRulesReveal { strategy_id, version } => {
+                let key = format!("{}:{}", strategy_id, version);
+                QueryResponse::RulesReveal(self.state.rules_reveals.get(&key).await.ok().flatten())
+            }
+            Query::GetForkTree { strategy_id, max_depth } => {
+                QueryResponse::ForkTree(self.get_fork_tree(strategy_id, max_depth).await)
+            }
+            Query::GetMicrochainProfile { wallet, viewer } => {
+                QueryResponse::MicrochainProfile(self.get_microchain_profile(wallet, viewer).await)
+            }
+            Query::GetProfileRegistry => QueryResponse::ProfileRegistry(ProfileRegistry {
+                enabled: *self.state.registry_mode.get(),
+                registry_chain: *self.state.registry_chain.get(),
+                profile_chain_balance: *self.state.profile_chain_balance.get(),
+            }),
+            Query::GetProfileCreationNonce { wallet } => QueryResponse::ProfileCreationNonce(
+                self.state.retired_profile_nonces.get(&wallet).await.ok().flatten().unwrap_or(0),
+            ),
+            Query::GetFollow { strategy_id, follower_id } => {
+                QueryResponse::Follow(self.get_follow(strategy_id, follower_id).await)
+            }
+            Query::GetFollowers { strategy_id, limit, offset } => {
+                QueryResponse::Follows(self.get_followers(strategy_id, limit, offset).await)
+            }
+            Query::GetFollowing { follower_id, limit, offset } => {
+                QueryResponse::Follows(self.get_following(follower_id, limit, offset).await)
+            }
+            Query::GetReplication { id } => {
+                QueryResponse::Replication(self.state.trade_replications.get(&id).await.ok().flatten())
+            }
+            Query::GetReplications { filter, limit, offset } => {
+                QueryResponse::Replications(self.get_replications(filter, limit, offset).await)
+            }
+            Query::GetFeeAccount { strategy_id, follower_id } => {
+                let key = format!("{}:{}", strategy_id, follower_id);
+                QueryResponse::FeeAccount(self.state.fee_accounts.get(&key).await.ok().flatten())
             }
-            Query::GetMicrochainProfile { wallet } => {
-                QueryResponse::MicrochainProfile(self.get_microchain_profile(wallet).await)
+            Query::GetFeeAccounts { party } => QueryResponse::FeeAccounts(self.get_fee_accounts(party).await),
+            Query::GetFeeLedger { party, limit, offset } => {
+                QueryResponse::FeeLedger(self.get_fee_ledger(party, limit, offset).await)
+            }
+            Query::GetStrategyRiskMetrics { strategy_id } => {
+                let stats = self.state.strategy_performance.get(&strategy_id).await.ok().flatten();
+                QueryResponse::RiskMetrics(stats.map(|stats| stats.metrics()))
+            }
+            Query::GetProfileRiskMetrics { owner, viewer } => {
+                if !self.can_view_profile_of(&owner, &viewer).await {
+                    return QueryResponse::RiskMetrics(None);
+                }
+                let stats = self.state.profile_performance.get(&owner).await.ok().flatten();
+                QueryResponse::RiskMetrics(stats.map(|stats| stats.metrics()))
+            }
+            Query::GetStrategyEquity { strategy_id, limit } => {
+                let curve = self.state.strategy_equity.get(&strategy_id).await.ok().flatten();
+                QueryResponse::EquitySeries(latest_points(curve, limit))
+            }
+            Query::GetProfileEquity { owner, limit, viewer } => {
+                if !self.can_view_profile_of(&owner, &viewer).await {
+                    return QueryResponse::EquitySeries(Vec::new());
+                }
+                let curve = self.state.profile_equity.get(&owner).await.ok().flatten();
+                QueryResponse::EquitySeries(latest_points(curve, limit))
+            }
+            Query::GetLeaderboard { sort_by, min_trades, limit } => {
+                QueryResponse::Leaderboard(self.get_leaderboard(sort_by, min_trades, limit).await)
             }
             Query::GetNetworkAnalytics => {
                 QueryResponse::NetworkAnalytics(self.get_network_analytics().await)
@@ -103,11 +206,60 @@ impl LineraTradeService {
         self.state.signals.get(&id).await.ok().flatten()
     }
 
+    async fn get_signal_outcome(&self, signal_id: u64) -> Option<SignalOutcome> {
+        self.state.signal_outcomes.get(&signal_id).await.ok().flatten()
+    }
+
+    async fn get_signal_feed(&self, limit: usize, offset: usize) -> Vec<SignalWithOutcome> {
+        let mut feed = Vec::new();
+        for signal in self.get_signals(limit, offset).await {
+            let outcome = self.state.signal_outcomes.get(&signal.id).await.ok().flatten();
+            feed.push(SignalWithOutcome { signal, outcome });
+        }
+        feed
+    }
+
+    async fn get_influencers(
+        &self,
+        min_score: Option<f64>,
+        min_signals: Option<u64>,
+        limit: usize,
+    ) -> Vec<InfluencerStats> {
+        let mut influencers: Vec<InfluencerStats> = self
+            .state
+            .influencers
+            .index_values()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(_, stats)| stats)
+            .filter(|stats| min_score.is_none_or(|min| stats.reputation_score >= min))
+            .filter(|stats| min_signals.is_none_or(|min| stats.total_signals >= min))
+            .collect();
+
+        influencers.sort_by(|a, b| b.reputation_score.total_cmp(&a.reputation_score));
+        influencers.truncate(limit);
+        influencers
+    }
+
+    async fn get_influencer(&self, influencer: String) -> Option<InfluencerStats> {
+        self.state.influencers.get(&influencer).await.ok().flatten()
+    }
+
+    async fn get_signal_submitters(&self) -> Vec<String> {
+        self.state.signal_submitters.indices().await.unwrap_or_default()
+    }
+
+    fn get_signal_policy(&self) -> SignalPolicy {
+        self.state.signal_policy.get().clone()
+    }
+
     async fn get_strategies(
         &self,
         owner: Option<String>,
         limit: usize,
         offset: usize,
+        viewer: Option<String>,
     ) -> Vec<Strategy> {
         let mut strategies = Vec::new();
         let counter = *self.state.strategy_counter.get();
@@ -124,10 +276,10 @@ impl LineraTradeService {
             if let Ok(Some(strategy)) = self.state.strategies.get(&id).await {
                 if let Some(ref filter_owner) = owner {
                     if &strategy.owner == filter_owner {
-                        strategies.push(strategy);
+                        strategies.push(self.for_viewer(strategy, &viewer).await);
                     }
                 } else {
-                    strategies.push(strategy);
+                    strategies.push(self.for_viewer(strategy, &viewer).await);
                 }
             }
         }
@@ -135,14 +287,38 @@ impl LineraTradeService {
         strategies
     }
 
-    async fn get_strategy(&self, id: u64) -> Option<Strategy> {
-        self.state.strategies.get(&id).await.ok().flatten()
+    async fn get_strategy(&self, id: u64, viewer: Option<String>) -> Option<Strategy> {
+        let strategy = self.state.strategies.get(&id).await.ok().flatten()?;
+        Some(self.for_viewer(strategy, &viewer).await)
+    }
+
+    /// Whether the strategy's visibility lets `viewer` read its rules.
+    /// `viewer` is whatever the client passed, so this only spares honest
+    /// clients rules they were not meant to see; it cannot keep them secret.
+    async fn can_view_rules(&self, strategy: &Strategy, viewer: &Option<String>) -> bool {
+        let Some(viewer) = viewer else {
+            return strategy.visibility.allows(false, false);
+        };
+        let is_owner = &strategy.owner == viewer;
+        let is_follower = match self.state.strategy_followers.try_load_entry(&strategy.id).await {
+            Ok(Some(followers)) => followers.contains_key(viewer).await.unwrap_or(false),
+            _ => false,
+        };
+        strategy.visibility.allows(is_owner, is_follower)
+    }
+
+    async fn for_viewer(&self, strategy: Strategy, viewer: &Option<String>) -> Strategy {
+        if self.can_view_rules(&strategy, viewer).await {
+            strategy
+        } else {
+            strategy.redacted()
+        }
     }
 
     async fn get_orders(
         &self,
         strategy_id: Option<u64>,
-        status: Option<String>,
+        status: Option<OrderStatus>,
         limit: usize,
         offset: usize,
     ) -> Vec<Order> {
@@ -168,8 +344,7 @@ impl LineraTradeService {
                 }
 
                 if let Some(ref filter_status) = status {
-                    let order_status = format!("{:?}", order.status);
-                    if &order_status != filter_status {
+                    if &order.status != filter_status {
                         include = false;
                     }
                 }
@@ -187,6 +362,42 @@ impl LineraTradeService {
         self.state.orders.get(&id).await.ok().flatten()
     }
 
+    async fn get_bracket(&self, entry_order_id: u64) -> Option<Bracket> {
+        self.state.brackets.get(&entry_order_id).await.ok().flatten()
+    }
+
+    async fn get_dex_order(&self, id: u64) -> Option<DEXOrder> {
+        self.state.dex_orders.get(&id).await.ok().flatten()
+    }
+
+    /// Child slices that keepers should execute now, oldest orders first
+    async fn get_due_order_slices(&self, limit: usize) -> Vec<DueSlice> {
+        let now = self.runtime.system_time().micros();
+        let mut due = Vec::new();
+        let counter = *self.state.dex_order_counter.get();
+
+        for id in 1..=counter {
+            if due.len() >= limit {
+                break;
+            }
+            if let Ok(Some(order)) = self.state.dex_orders.get(&id).await {
+                if let Some(slice) = order.due_slice(now) {
+                    due.push(DueSlice {
+                        order_id: order.id,
+                        slice_index: slice.index,
+                        input_mint: order.input_mint.clone(),
+                        output_mint: order.output_mint.clone(),
+                        input_amount: slice.input_amount,
+                        slippage_bps: order.slippage_bps,
+                        due_at: slice.due_at,
+                    });
+                }
+            }
+        }
+
+        due
+    }
+
     // Safety & Validation query methods
     async fn get_safety_config(&self, owner: String) -> Option<SafetyConfig> {
         self.state.safety_configs.get(&owner).await.ok().flatten()
@@ -231,17 +442,22 @@ impl LineraTradeService {
     }
 
     // Strategy Enhancement query methods (Phase 2)
-    async fn get_strategy_versions(&self, strategy_id: u64) -> Vec<StrategyVersion> {
+    async fn get_strategy_versions(&self, strategy_id: u64, viewer: Option<String>) -> Vec<StrategyVersion> {
         let mut versions = Vec::new();
         
-        // Get current strategy to find max version
+        // Get current strategy to find max version; its visibility covers
+        // every past version
         if let Ok(Some(current)) = self.state.strategies.get(&strategy_id).await {
             let max_version = current.version;
+            let can_view_rules = self.can_view_rules(&current, &viewer).await;
             
             // Iterate through all versions
             for version in 1..=max_version {
                 let version_key = format!("{}:{}", strategy_id, version);
-                if let Ok(Some(entry)) = self.state.strategy_versions.get(&version_key).await {
+                if let Ok(Some(mut entry)) = self.state.strategy_versions.get(&version_key).await {
+                    if !can_view_rules {
+                        entry.strategy_snapshot = entry.strategy_snapshot.redacted();
+                    }
                     versions.push(entry);
                 }
             }
@@ -250,8 +466,335 @@ impl LineraTradeService {
         versions
     }
 
-    async fn get_microchain_profile(&self, wallet: String) -> Option<MicrochainProfile> {
-        self.state.microchain_profiles.get(&wallet).await.ok().flatten()
+    /// The strategy as it was at `version`, including the current version
+    async fn strategy_at_version(&self, strategy_id: u64, version: u64) -> Option<Strategy> {
+        let current = self.state.strategies.get(&strategy_id).await.ok().flatten()?;
+        if current.version == version {
+            return Some(current);
+        }
+        let version_key = format!("{}:{}", strategy_id, version);
+        let entry = self.state.strategy_versions.get(&version_key).await.ok().flatten()?;
+        Some(entry.strategy_snapshot)
+    }
+
+    async fn get_strategy_diff(
+        &self,
+        strategy_id: u64,
+        from_version: u64,
+        to_version: u64,
+        viewer: Option<String>,
+    ) -> Option<StrategyDiff> {
+        let current = self.state.strategies.get(&strategy_id).await.ok().flatten()?;
+        let from = self.strategy_at_version(strategy_id, from_version).await?;
+        let to = self.strategy_at_version(strategy_id, to_version).await?;
+        let diff = StrategyDiff {
+            strategy_id,
+            from_version,
+            to_version,
+            changes: from.diff(&to),
+        };
+        if self.can_view_rules(&current, &viewer).await {
+            Some(diff)
+        } else {
+            Some(diff.redacted())
+        }
+    }
+
+    async fn get_strategy_reviews(&self, strategy_id: u64) -> Vec<CuratorReview> {
+        match self.state.strategy_reviews.try_load_entry(&strategy_id).await {
+            Ok(Some(reviews)) => reviews
+                .index_values()
+                .await
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(_, review)| review)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    async fn get_curated_strategies(
+        &self,
+        min_rating: f64,
+        limit: usize,
+        offset: usize,
+        viewer: Option<String>,
+    ) -> Vec<CuratedStrategy> {
+        let mut rated: Vec<_> = self
+            .state
+            .strategy_ratings
+            .index_values()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(_, ratings)| ratings.rating_count > 0 && ratings.average() >= min_rating)
+            .collect();
+        rated.sort_by(|(_, a), (_, b)| {
+            b.average()
+                .total_cmp(&a.average())
+                .then(b.rating_count.cmp(&a.rating_count))
+        });
+
+        let mut curated = Vec::new();
+        for (strategy_id, ratings) in rated.into_iter().skip(offset).take(limit) {
+            if let Ok(Some(strategy)) = self.state.strategies.get(&strategy_id).await {
+                curated.push(CuratedStrategy {
+                    strategy: self.for_viewer(strategy, &viewer).await,
+                    average_rating: ratings.average(),
+                    ratings,
+                });
+            }
+        }
+        curated
+    }
+
+    async fn get_fork_tree(&self, strategy_id: u64, max_depth: u32) -> Option<ForkTree> {
+        let strategy = self.state.strategies.get(&strategy_id).await.ok().flatten()?;
+
+        // Parents always have lower ids, so the walk up terminates
+        let mut ancestors = Vec::new();
+        let mut origin = strategy.forked_from.clone();
+        while let Some(parent_id) = origin.map(|origin| origin.strategy_id) {
+            let Ok(Some(parent)) = self.state.strategies.get(&parent_id).await else {
+                break;
+            };
+            ancestors.push(ForkNode::new(&parent, -(ancestors.len() as i32) - 1));
+            origin = parent.forked_from;
+        }
+        ancestors.reverse();
+
+        let mut descendants = Vec::new();
+        let mut level = vec![strategy_id];
+        for depth in 1..=max_depth {
+            let mut next_level = Vec::new();
+            for parent_id in level {
+                let Ok(Some(forks)) = self.state.strategy_forks.try_load_entry(&parent_id).await else {
+                    continue;
+                };
+                let mut fork_ids = forks.indices().await.unwrap_or_default();
+                fork_ids.sort_unstable();
+                for fork_id in fork_ids {
+                    if let Ok(Some(fork)) = self.state.strategies.get(&fork_id).await {
+                        descendants.push(ForkNode::new(&fork, depth as i32));
+                        next_level.push(fork_id);
+                    }
+                }
+            }
+            if next_level.is_empty() {
+                break;
+            }
+            level = next_level;
+        }
+
+        Some(ForkTree {
+            ancestors,
+            strategy: ForkNode::new(&strategy, 0),
+            descendants,
+        })
+    }
+
+    async fn get_microchain_profile(&self, wallet: String, viewer: Option<String>) -> Option<MicrochainProfile> {
+        let profile = self.profile_of(wallet).await?;
+        if self.can_view_profile(&profile, &viewer).await {
+            Some(profile)
+        } else {
+            Some(profile.redacted())
+        }
+    }
+
+    /// The profile any linked wallet resolves to, unredacted
+    async fn profile_of(&self, wallet: String) -> Option<MicrochainProfile> {
+        let profile_id = self.state.wallet_profiles.get(&wallet).await.ok().flatten().unwrap_or(wallet);
+        self.state.microchain_profiles.get(&profile_id).await.ok().flatten()
+    }
+
+    /// Whether the profile's visibility lets `viewer` see its wallets and
+    /// stats. `viewer` is not authenticated, so this redaction is advisory:
+    /// anyone naming the owner or a follower sees the full profile.
+    async fn can_view_profile(&self, profile: &MicrochainProfile, viewer: &Option<String>) -> bool {
+        let Some(viewer) = viewer else {
+            return profile.visibility.allows(false, false);
+        };
+        let is_manager = profile.is_managed_by(viewer);
+        let is_follower = profile.visibility == ProfileVisibility::Followers
+            && !is_manager
+            && self.follows_any_strategy_of(viewer, &profile.wallets).await;
+        profile.visibility.allows(is_manager, is_follower)
+    }
+
+    /// Stats of a trader without a profile are public
+    async fn can_view_profile_of(&self, owner: &str, viewer: &Option<String>) -> bool {
+        match self.profile_of(owner.to_string()).await {
+            Some(profile) => self.can_view_profile(&profile, viewer).await,
+            None => true,
+        }
+    }
+
+    async fn follows_any_strategy_of(&self, follower_id: &String, owners: &[String]) -> bool {
+        let Ok(Some(following)) = self.state.following.try_load_entry(follower_id).await else {
+            return false;
+        };
+        for strategy_id in following.indices().await.unwrap_or_default() {
+            if let Ok(Some(strategy)) = self.state.strategies.get(&strategy_id).await {
+                if owners.contains(&strategy.owner) {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    async fn get_follow(&self, strategy_id: u64, follower_id: String) -> Option<FollowSummary> {
+        let followers = self.state.strategy_followers.try_load_entry(&strategy_id).await.ok().flatten()?;
+        let follower = followers.get(&follower_id).await.ok().flatten()?;
+        Some(self.follow_summary(follower).await)
+    }
+
+    async fn follow_summary(&self, follower: StrategyFollower) -> FollowSummary {
+        let key = format!("{}:{}", follower.strategy_id, follower.follower_id);
+        let fees = self.state.fee_accounts.get(&key).await.ok().flatten();
+        FollowSummary { follower, fees }
+    }
+
+    async fn get_followers(&self, strategy_id: u64, limit: usize, offset: usize) -> Vec<FollowSummary> {
+        let Ok(Some(followers)) = self.state.strategy_followers.try_load_entry(&strategy_id).await else {
+            return Vec::new();
+        };
+        let page: Vec<StrategyFollower> = followers
+            .index_values()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .map(|(_, follower)| follower)
+            .collect();
+
+        let mut summaries = Vec::with_capacity(page.len());
+        for follower in page {
+            summaries.push(self.follow_summary(follower).await);
+        }
+        summaries
+    }
+
+    async fn get_following(&self, follower_id: String, limit: usize, offset: usize) -> Vec<FollowSummary> {
+        let Ok(Some(following)) = self.state.following.try_load_entry(&follower_id).await else {
+            return Vec::new();
+        };
+        let strategy_ids = following.indices().await.unwrap_or_default();
+
+        let mut summaries = Vec::new();
+        for strategy_id in strategy_ids.into_iter().skip(offset).take(limit) {
+            if let Some(summary) = self.get_follow(strategy_id, follower_id.clone()).await {
+                summaries.push(summary);
+            }
+        }
+        summaries
+    }
+
+    async fn get_replications(&self, filter: ReplicationFilter, limit: usize, offset: usize) -> Vec<TradeReplication> {
+        let mut ids = match filter {
+            ReplicationFilter::OriginalOrder(order_id) => {
+                match self.state.replications_by_original.try_load_entry(&order_id).await {
+                    Ok(Some(ids)) => ids.indices().await.unwrap_or_default(),
+                    _ => Vec::new(),
+                }
+            }
+            ReplicationFilter::Follower(follower_id) => {
+                match self.state.replications_by_follower.try_load_entry(&follower_id).await {
+                    Ok(Some(ids)) => ids.indices().await.unwrap_or_default(),
+                    _ => Vec::new(),
+                }
+            }
+            ReplicationFilter::Strategy(strategy_id) => {
+                match self.state.replications_by_strategy.try_load_entry(&strategy_id).await {
+                    Ok(Some(ids)) => ids.indices().await.unwrap_or_default(),
+                    _ => Vec::new(),
+                }
+            }
+        };
+
+        // Set indices are not in numeric order
+        ids.sort_unstable_by(|a, b| b.cmp(a));
+
+        let mut replications = Vec::new();
+        for id in ids.into_iter().skip(offset).take(limit) {
+            if let Ok(Some(replication)) = self.state.trade_replications.get(&id).await {
+                replications.push(replication);
+            }
+        }
+        replications
+    }
+
+    async fn get_fee_accounts(&self, party: String) -> Vec<FollowerFeeAccount> {
+        self.state
+            .fee_accounts
+            .index_values()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(_, account)| account)
+            .filter(|account| account.follower_id == party || account.author == party)
+            .collect()
+    }
+
+    async fn get_fee_ledger(&self, party: String, limit: usize, offset: usize) -> Vec<FeeLedgerEntry> {
+        let mut entries = Vec::new();
+        let mut skipped = 0;
+        let mut id = *self.state.fee_ledger_counter.get();
+        while id > 0 && entries.len() < limit {
+            if let Ok(Some(entry)) = self.state.fee_ledger.get(&id).await {
+                if entry.follower_id == party || entry.author == party {
+                    if skipped < offset {
+                        skipped += 1;
+                    } else {
+                        entries.push(entry);
+                    }
+                }
+            }
+            id -= 1;
+        }
+        entries
+    }
+
+    /// Profiles ranked by a risk metric, built from closed-trade statistics
+    async fn get_leaderboard(&self, sort_by: LeaderboardSort, min_trades: u64, limit: usize) -> Vec<LeaderboardEntry> {
+        let performance: Vec<(String, PerformanceStats)> = self
+            .state
+            .profile_performance
+            .index_values()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(_, stats)| stats.trades >= min_trades)
+            .collect();
+
+        let mut leaderboard = Vec::with_capacity(performance.len());
+        for (owner, stats) in performance {
+            // The leaderboard is public, so only profiles anyone may see are
+            // ranked
+            let profile = self.profile_of(owner.clone()).await;
+            if profile.as_ref().is_some_and(|p| !p.visibility.allows(false, false)) {
+                continue;
+            }
+            let risk = stats.metrics();
+            leaderboard.push(LeaderboardEntry {
+                name: profile.as_ref().map_or_else(|| owner.clone(), |p| p.name.clone()),
+                chain: profile
+                    .and_then(|p| p.preferred_chains.first().cloned())
+                    .unwrap_or_default(),
+                id: owner,
+                win_rate: risk.win_rate,
+                roi: risk.roi,
+                trades: stats.trades,
+                volume: stats.total_volume.round() as u64,
+                risk,
+            });
+        }
+
+        leaderboard.sort_by(|a, b| sort_by.key(&b.risk).total_cmp(&sort_by.key(&a.risk)));
+        leaderboard.truncate(limit);
+        leaderboard
     }
 
     async fn get_network_analytics(&self) -> NetworkAnalytics {
@@ -260,14 +803,10 @@ impl LineraTradeService {
         let total_volume = *self.state.total_network_volume.get();
         let active_trades = *self.state.order_counter.get();
 
-        // Build leaderboard from top microchain profiles
-        // This is a simplified implementation - in production you'd want indexed queries
-        let mut leaderboard = Vec::new();
-        
-        // Iterate through microchain profiles to build leaderboard
-        // Note: MapView doesn't have direct iteration, so we collect based on known wallets
-        // For now, return empty leaderboard - real implementation would need indexing
-        
+        let leaderboard = self
+            .get_leaderboard(LeaderboardSort::default(), 1, NETWORK_LEADERBOARD_SIZE)
+            .await;
+
         NetworkAnalytics {
             total_microchains,
             total_strategies,
@@ -277,3 +816,10 @@ impl LineraTradeService {
         }
     }
 }
+
+/// The most recent `limit` points of a curve, oldest first
+fn latest_points(curve: Option<Vec<EquityPoint>>, limit: usize) -> Vec<EquityPoint> {
+    let mut curve = curve.unwrap_or_default();
+    curve.drain(..curve.len().saturating_sub(limit));
+    curve
+}
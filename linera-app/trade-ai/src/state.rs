@@ -1,5 +1,6 @@
-use linera_sdk::views::{MapView, RegisterView, RootView, ViewStorageContext};
-use abi::{Signal, Strategy, Order, DEXOrder, StrategyFollower, TradeReplication, SafetyConfig, ValidatedOrder, PredictionMarket, StrategyMarketLink, StrategyVersion, MicrochainProfile};
+use linera_sdk::linera_base_types::{Amount, ChainId};
+use linera_sdk::views::{CollectionView, MapView, QueueView, RegisterView, RootView, SetView, ViewStorageContext};
+use abi::{Signal, Strategy, Order, DEXOrder, StrategyFollower, TradeReplication, SafetyConfig, ValidatedOrder, PredictionMarket, StrategyMarketLink, StrategyVersion, MicrochainProfile, Bracket, SignalOutcome, InfluencerStats, SignalPolicy, RateWindow, PerformanceStats, EquityPoint, FollowerFeeAccount, FeeLedgerEntry, RulesReveal, CuratorReview, RatingSummary};
 
 /// Application state
 #[derive(RootView)]
@@ -7,11 +8,31 @@ use abi::{Signal, Strategy, Order, DEXOrder, StrategyFollower, TradeReplication,
 pub struct LineraTradeState {
     // Core trading state
     pub signals: MapView<u64, Signal>,
+    pub signal_outcomes: MapView<u64, SignalOutcome>, // signal_id -> outcome
+    pub influencers: MapView<String, InfluencerStats>, // influencer -> reputation
+    pub open_signals: CollectionView<String, QueueView<u64>>, // contract -> unresolved signal ids, next to evaluate first
+
+    // Signal dedupe and anti-spam
+    pub signal_by_tweet: MapView<String, u64>,          // tweet_url -> signal_id
+    pub last_call: MapView<String, (u64, u64)>,         // "influencer:contract" -> (signal_id, submitted_at)
+    pub signal_submitters: MapView<String, ()>,         // authorised submitters
+    pub submitter_windows: MapView<String, RateWindow>, // submitter -> rate window
+    pub signal_policy: RegisterView<SignalPolicy>,
     pub strategies: MapView<u64, Strategy>,
     pub orders: MapView<u64, Order>,
     pub dex_orders: MapView<u64, DEXOrder>,
-    pub strategy_followers: MapView<Vec<u8>, StrategyFollower>,
-    pub trade_replications: MapView<u64, TradeReplication>,
+    pub strategy_followers: CollectionView<u64, MapView<String, StrategyFollower>>, // strategy_id -> follower_id -> follow
+    pub following: CollectionView<String, SetView<u64>>,                              // follower_id -> strategy ids
+    pub trade_replications: MapView<u64, TradeReplication>, // replication id -> record
+    pub replication_by_order: MapView<u64, u64>,            // follower order id -> replication id
+    pub replications_by_original: CollectionView<u64, SetView<u64>>,    // leader order id -> replication ids
+    pub replications_by_follower: CollectionView<String, SetView<u64>>, // follower_id -> replication ids
+    pub replications_by_strategy: CollectionView<u64, SetView<u64>>,    // strategy_id -> replication ids
+    pub brackets: MapView<u64, Bracket>, // entry order_id -> bracket
+
+    // Strategy fees
+    pub fee_accounts: MapView<String, FollowerFeeAccount>, // "strategy_id:follower_id" -> account
+    pub fee_ledger: MapView<u64, FeeLedgerEntry>,
     
     // Safety & Validation state (Phase 1)
     pub safety_configs: MapView<String, SafetyConfig>,  // owner -> config
@@ -23,9 +44,37 @@ pub struct LineraTradeState {
     
     // Strategy Enhancement state (Phase 2)
     pub strategy_versions: MapView<String, StrategyVersion>, // "strategy_id:version" -> snapshot
+    pub strategy_forks: CollectionView<u64, SetView<u64>>,   // strategy_id -> ids of its direct forks
+    pub rules_reveals: MapView<String, RulesReveal>,         // "strategy_id:version" -> revealed rules
+
+    // Curation
+    pub curators: MapView<String, ()>,
+    pub strategy_reviews: CollectionView<u64, MapView<String, CuratorReview>>, // strategy_id -> curator -> review
+    pub curator_reviews: CollectionView<String, SetView<u64>>,                  // curator -> reviewed strategy ids
+    pub strategy_ratings: MapView<u64, RatingSummary>,
     
     // Microchain Profiles
-    pub microchain_profiles: MapView<String, MicrochainProfile>, // wallet -> profile
+    pub microchain_profiles: MapView<String, MicrochainProfile>, // profile id -> profile
+    pub wallet_profiles: MapView<String, String>,                // linked wallet -> profile id
+    pub retired_profile_nonces: MapView<String, u64>,            // deleted profile id -> next proof nonce
+    pub registry_mode: RegisterView<bool>,                       // open a chain per new profile
+    pub registry_chain: RegisterView<Option<ChainId>>,           // set on profile chains
+    pub profile_chain_balance: RegisterView<Amount>,             // funds each new profile chain
+
+    // Risk metrics from closed trades
+    pub strategy_performance: MapView<u64, PerformanceStats>,
+    pub strategy_equity: MapView<u64, Vec<EquityPoint>>,
+    pub profile_performance: MapView<String, PerformanceStats>, // strategy owner -> stats
+    pub profile_equity: MapView<String, Vec<EquityPoint>>,
+
+    // Idempotency
+    pub client_requests: MapView<String, u64>, // "signer:kind:client_request_id" -> assigned id
+    pub processed_fills: MapView<String, ()>,  // "kind:order_id:tx" -> recorded
+
+    // Configuration
+    pub admin: RegisterView<Option<String>>,
+    pub max_batch_size: RegisterView<u32>, // 0 = DEFAULT_MAX_BATCH_SIZE
+    pub immediate_order_window: RegisterView<u64>, // 0 = DEFAULT_IMMEDIATE_ORDER_WINDOW_MICROS
 
     // Counters
     pub signal_counter: RegisterView<u64>,
@@ -35,5 +84,12 @@ pub struct LineraTradeState {
     pub market_counter: RegisterView<u64>,
     pub microchain_counter: RegisterView<u64>,
     pub total_network_volume: RegisterView<u64>,
+    pub fee_ledger_counter: RegisterView<u64>,
+    pub replication_counter: RegisterView<u64>,
+
+    // Expiry sweep positions (last order id scanned)
+    pub order_expiry_cursor: RegisterView<u64>,
+    pub dex_order_expiry_cursor: RegisterView<u64>,
+    pub signal_expiry_cursor: RegisterView<u64>,
 }
 
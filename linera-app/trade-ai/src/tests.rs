@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod microchain_profile_tests {
+    use super::super::*;
+    use linera_sdk::linera_base_types::{AccountOwner, AccountSecretKey, ApplicationId, Ed25519SecretKey, Timestamp};
+    use linera_sdk::util::BlockingWait;
+
+    fn secret_key(byte: u8) -> AccountSecretKey {
+        let hex = format!("\"{}\"", format!("{:02x}", byte).repeat(32));
+        AccountSecretKey::Ed25519(serde_json::from_str::<Ed25519SecretKey>(&hex).unwrap())
+    }
+
+    fn chain_id() -> ChainId {
+        "aa".repeat(32).parse().unwrap()
+    }
+
+    fn application_id() -> ApplicationId {
+        "cc".repeat(32).parse().unwrap()
+    }
+
+    fn contract(signer: AccountOwner) -> LineraTradeContract {
+        let runtime = ContractRuntime::new()
+            .with_application_parameters(())
+            .with_chain_id(chain_id())
+            .with_application_id(application_id().with_abi())
+            .with_system_time(Timestamp::from(0))
+            .with_authenticated_signer(signer);
+        LineraTradeContract::load(runtime).blocking_wait()
+    }
+
+    fn create(wallet: &str, signature: AccountSignature) -> Operation {
+        Operation::CreateMicrochainProfile {
+            name: "trader".to_string(),
+            wallet: wallet.to_string(),
+            chains: Vec::new(),
+            visibility: ProfileVisibility::Public,
+            signature,
+        }
+    }
+
+    fn creation_signature(key: &AccountSecretKey, wallet: &str, nonce: u64) -> AccountSignature {
+        key.sign(&WalletLinkProof {
+            chain_id: chain_id(),
+            application_id: application_id(),
+            profile_id: wallet.to_string(),
+            wallet: wallet.to_string(),
+            nonce,
+        })
+    }
+
+    #[test]
+    fn test_creation_proof_cannot_be_replayed_after_delete() {
+        let key = secret_key(1);
+        let owner = AccountOwner::from(key.public());
+        let wallet = owner.to_string();
+        let mut contract = contract(owner);
+
+        let signature = creation_signature(&key, &wallet, 0);
+        contract.execute_operation(create(&wallet, signature)).blocking_wait();
+        assert!(contract.state.microchain_profiles.contains_key(&wallet).blocking_wait().unwrap());
+
+        contract
+            .execute_operation(Operation::DeleteMicrochainProfile { profile_id: wallet.clone() })
+            .blocking_wait();
+        assert!(!contract.state.microchain_profiles.contains_key(&wallet).blocking_wait().unwrap());
+
+        // Someone else replays the public creation signature
+        contract.runtime.set_authenticated_signer(AccountOwner::from(secret_key(2).public()));
+        contract.execute_operation(create(&wallet, signature)).blocking_wait();
+        assert!(!contract.state.microchain_profiles.contains_key(&wallet).blocking_wait().unwrap());
+
+        // The wallet signs afresh at the carried-over nonce
+        contract.runtime.set_authenticated_signer(owner);
+        contract.execute_operation(create(&wallet, creation_signature(&key, &wallet, 1))).blocking_wait();
+        let profile = contract.state.microchain_profiles.get(&wallet).blocking_wait().unwrap().unwrap();
+        assert_eq!((profile.owner, profile.link_nonce), (wallet, 2));
+    }
+
+    #[test]
+    fn test_creation_needs_the_wallet_signature() {
+        let wallet = AccountOwner::from(secret_key(1).public()).to_string();
+        let mut contract = contract(AccountOwner::from(secret_key(2).public()));
+
+        let squatted = creation_signature(&secret_key(2), &wallet, 0);
+        contract.execute_operation(create(&wallet, squatted)).blocking_wait();
+        assert!(!contract.state.microchain_profiles.contains_key(&wallet).blocking_wait().unwrap());
+    }
+
+    #[test]
+    fn test_registry_forwards_trades_of_hosted_profiles() {
+        let key = secret_key(1);
+        let owner = AccountOwner::from(key.public());
+        let wallet = owner.to_string();
+        let mut contract = contract(owner);
+        contract.execute_operation(create(&wallet, creation_signature(&key, &wallet, 0))).blocking_wait();
+
+        // The profile has since moved to a chain of its own
+        let hosted: ChainId = "bb".repeat(32).parse().unwrap();
+        let mut profile = contract.state.microchain_profiles.get(&wallet).blocking_wait().unwrap().unwrap();
+        profile.chain_id = Some(hosted);
+        contract.state.microchain_profiles.insert(&wallet, profile).unwrap();
+
+        let trade = ClosedTrade {
+            strategy_id: 1,
+            entry_order_id: 1,
+            exit_order_id: 2,
+            quantity: 1.0,
+            entry_price: 100.0,
+            exit_price: 110.0,
+            is_short: false,
+            opened_at: 0,
+            closed_at: 10,
+        };
+        contract.record_profile_trade(&wallet, &trade).blocking_wait();
+
+        assert!(contract.state.profile_performance.get(&wallet).blocking_wait().unwrap().is_none());
+        let sent = contract.runtime.created_send_message_requests();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].destination, hosted);
+        assert!(matches!(
+            &sent[0].message,
+            Message::RecordProfileTrade { owner, trade } if owner == &wallet && trade.exit_order_id == 2
+        ));
+    }
+}